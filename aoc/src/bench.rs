@@ -0,0 +1,41 @@
+use common::bench::{bench, Stats, Timings};
+use common::error::ParseError;
+use common::input::{self, Source};
+use common::report::Format;
+
+type Bencher = fn(&str, usize) -> Result<Timings, ParseError>;
+
+const BENCHES: [Bencher; 10] = [
+    bench::<day_01::Day01>,
+    bench::<day_02::Day02>,
+    bench::<day_03::Day03>,
+    bench::<day_04::Day04>,
+    bench::<day_05::Day05>,
+    bench::<day_06::Day06>,
+    bench::<day_07::Day07>,
+    bench::<day_08::Day08>,
+    bench::<day_09::Day09>,
+    bench::<day_10::Day10>,
+];
+
+/// time a day on each input, printing the spread of each phase's timings
+pub fn bench_day(day: usize, iterations: usize, sources: &[Source]) -> bool {
+    input::for_each(sources, Format::Text, |_, input| {
+        let timings = BENCHES[day - 1](input, iterations)?;
+
+        println!("Day {:02} over {} iterations", day, iterations);
+        for (phase, timings) in [
+            ("parse", &timings.parse),
+            ("part 1", &timings.part_one),
+            ("part 2", &timings.part_two),
+        ] {
+            if let Some(stats) = Stats::of(timings) {
+                println!(
+                    "  {:<6}  min {:>10.1?}  median {:>10.1?}  max {:>10.1?}",
+                    phase, stats.min, stats.median, stats.max
+                );
+            }
+        }
+        Ok(())
+    })
+}
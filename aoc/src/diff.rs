@@ -0,0 +1,25 @@
+use common::reference::{differential, Disagreement};
+
+type Differential = fn(u64, usize) -> Option<Disagreement>;
+
+/// the days with reference solutions to check their shortcuts against
+pub const REFERENCES: [(usize, Differential); 4] = [
+    (5, differential::<day_05::Day05>),
+    (6, differential::<day_06::Day06>),
+    (8, differential::<day_08::Day08>),
+    (10, differential::<day_10::Day10>),
+];
+
+/// compare a day's fast and reference solutions, printing the first input they disagree on
+pub fn diff_day(day: usize, differential: Differential, cases: u64, max_size: usize) -> bool {
+    match differential(cases, max_size) {
+        None => {
+            println!("PASS day {:02}: {} inputs agree", day, cases);
+            true
+        }
+        Some(disagreement) => {
+            println!("FAIL day {:02} {}", day, disagreement);
+            false
+        }
+    }
+}
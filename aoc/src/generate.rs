@@ -0,0 +1,21 @@
+use common::generator::generate;
+
+type Generate = fn(u64, usize) -> String;
+
+const GENERATORS: [Generate; 10] = [
+    generate::<day_01::Day01>,
+    generate::<day_02::Day02>,
+    generate::<day_03::Day03>,
+    generate::<day_04::Day04>,
+    generate::<day_05::Day05>,
+    generate::<day_06::Day06>,
+    generate::<day_07::Day07>,
+    generate::<day_08::Day08>,
+    generate::<day_09::Day09>,
+    generate::<day_10::Day10>,
+];
+
+/// a random input for the day, the same every time for the same seed and size
+pub fn generate_day(day: usize, seed: u64, size: usize) -> String {
+    GENERATORS[day - 1](seed, size)
+}
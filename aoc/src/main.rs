@@ -0,0 +1,294 @@
+// Advent of Code 2023
+// https://adventofcode.com/2023
+// Usage: `cargo run -p aoc -- run <day> [--part 1|2] [--format text|json] [<input-file>|-]...
+//        `cargo run -p aoc -- run all [--part 1|2] [--format text|json]
+//        `cargo run -p aoc -- verify [<day>|all]
+//        `cargo run --release -p aoc -- bench [<day>|all] [--iterations <n>] [<input-file>|-]...
+//        `cargo run -p aoc -- generate <day> [--seed <n>] [--size <n>]
+//        `cargo run --release -p aoc -- diff [<day>|all] [--cases <n>] [--size <n>]
+
+mod bench;
+mod diff;
+mod generate;
+mod verify;
+
+use common::error::ParseError;
+use common::input::{self, Source};
+use common::report::{Format, Record};
+use common::solution::{solve, Part, Solved};
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+
+type Solver = fn(&str, Option<Part>) -> Result<Vec<Solved>, ParseError>;
+
+const DAYS: [Solver; 10] = [
+    solve::<day_01::Day01>,
+    solve::<day_02::Day02>,
+    solve::<day_03::Day03>,
+    solve::<day_04::Day04>,
+    solve::<day_05::Day05>,
+    solve::<day_06::Day06>,
+    solve::<day_07::Day07>,
+    solve::<day_08::Day08>,
+    solve::<day_09::Day09>,
+    solve::<day_10::Day10>,
+];
+
+const USAGE: &str = "usage: aoc run <day> [--part 1|2] [--format text|json] [<input-file>|-]...
+       aoc run all [--part 1|2] [--format text|json]
+       aoc verify [<day>|all]
+       aoc bench [<day>|all] [--iterations <n>] [<input-file>|-]...
+       aoc generate <day> [--seed <n>] [--size <n>]
+       aoc diff [<day>|all] [--cases <n>] [--size <n>]";
+
+const DEFAULT_ITERATIONS: usize = 100;
+const DEFAULT_SIZE: usize = 100;
+const DEFAULT_CASES: u64 = 100;
+const DEFAULT_DIFF_SIZE: usize = 10;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("generate") => generate(&args[1..]),
+        Some("diff") => diff(&args[1..]),
+        _ => usage(),
+    }
+}
+
+fn run(args: &[String]) {
+    let mut day = None;
+    let mut part = None;
+    let mut format = Format::Text;
+    let mut paths = vec![];
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => match args.next().map(|part| part.parse()) {
+                Some(Ok(value)) => part = Some(value),
+                _ => usage(),
+            },
+            "--format" => match args.next().map(|format| format.parse()) {
+                Some(Ok(value)) => format = value,
+                _ => usage(),
+            },
+            _ if day.is_none() => day = Some(arg.as_str()),
+            _ => paths.push(arg.as_str()),
+        }
+    }
+
+    let succeeded = match day {
+        Some("all") => {
+            let failures = (1..=DAYS.len())
+                .filter(|&day| !run_day(day, part, format, &[default_input(day)]))
+                .count();
+            failures == 0
+        }
+        Some(day) => {
+            let day = parse_day(day);
+            let sources = if paths.is_empty() {
+                vec![default_input(day)]
+            } else {
+                input::sources(paths).unwrap_or_else(|_| usage())
+            };
+            run_day(day, part, format, &sources)
+        }
+        None => usage(),
+    };
+
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+/// print the answers for a day from each input, or a diagnostic for any that can't be used
+fn run_day(day: usize, part: Option<Part>, format: Format, sources: &[Source]) -> bool {
+    input::for_each(sources, format, |source, input| {
+        for solved in DAYS[day - 1](input, part)? {
+            match format {
+                Format::Text => println!("Day {} part {}: {}", day, solved.part, solved.answer),
+                Format::Json => println!(
+                    "{}",
+                    Record {
+                        day,
+                        input: source,
+                        solved: &solved,
+                    }
+                ),
+            }
+        }
+        Ok(())
+    })
+}
+
+fn verify(args: &[String]) {
+    let days = match args {
+        [] => 1..=DAYS.len(),
+        [day] if day == "all" => 1..=DAYS.len(),
+        [day] => {
+            let day = parse_day(day);
+            day..=day
+        }
+        _ => usage(),
+    };
+
+    let mut failures = 0;
+    let mut passes = 0;
+
+    for day in days {
+        let (passed, failed) = verify::verify_day(day, DAYS[day - 1]);
+        passes += passed;
+        failures += failed;
+    }
+
+    println!("{} passed, {} failed", passes, failures);
+
+    if failures > 0 {
+        process::exit(1);
+    }
+}
+
+fn bench(args: &[String]) {
+    let mut day = None;
+    let mut iterations = DEFAULT_ITERATIONS;
+    let mut paths = vec![];
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" => match args.next().map(|n| n.parse()) {
+                Some(Ok(n)) if n > 0 => iterations = n,
+                _ => usage(),
+            },
+            _ if day.is_none() => day = Some(arg.as_str()),
+            _ => paths.push(arg.as_str()),
+        }
+    }
+
+    let succeeded = match day {
+        None | Some("all") if paths.is_empty() => {
+            let failures = (1..=DAYS.len())
+                .filter(|&day| !bench::bench_day(day, iterations, &[default_input(day)]))
+                .count();
+            failures == 0
+        }
+        Some(day) if day != "all" => {
+            let day = parse_day(day);
+            let sources = if paths.is_empty() {
+                vec![default_input(day)]
+            } else {
+                input::sources(paths).unwrap_or_else(|_| usage())
+            };
+            bench::bench_day(day, iterations, &sources)
+        }
+        _ => usage(),
+    };
+
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+fn generate(args: &[String]) {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = DEFAULT_SIZE;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => match args.next().map(|n| n.parse()) {
+                Some(Ok(n)) => seed = n,
+                _ => usage(),
+            },
+            "--size" => match args.next().map(|n| n.parse()) {
+                Some(Ok(n)) => size = n,
+                _ => usage(),
+            },
+            _ if day.is_none() => day = Some(parse_day(arg)),
+            _ => usage(),
+        }
+    }
+
+    match day {
+        Some(day) => print!("{}", generate::generate_day(day, seed, size)),
+        None => usage(),
+    }
+}
+
+fn diff(args: &[String]) {
+    let mut day = None;
+    let mut cases = DEFAULT_CASES;
+    let mut max_size = DEFAULT_DIFF_SIZE;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--cases" => match args.next().map(|n| n.parse()) {
+                Some(Ok(n)) => cases = n,
+                _ => usage(),
+            },
+            "--size" => match args.next().map(|n| n.parse()) {
+                Some(Ok(n)) if n > 0 => max_size = n,
+                _ => usage(),
+            },
+            _ if day.is_none() => day = Some(arg.as_str()),
+            _ => usage(),
+        }
+    }
+
+    let references: Vec<_> = match day {
+        None | Some("all") => diff::REFERENCES.to_vec(),
+        Some(day) => {
+            let day = parse_day(day);
+            match diff::REFERENCES
+                .iter()
+                .find(|(with_reference, _)| *with_reference == day)
+            {
+                Some(&reference) => vec![reference],
+                None => {
+                    eprintln!("day {} has no reference solution", day);
+                    process::exit(2);
+                }
+            }
+        }
+    };
+
+    let failures = references
+        .into_iter()
+        .filter(|&(day, differential)| !diff::diff_day(day, differential, cases, max_size))
+        .count();
+
+    if failures > 0 {
+        process::exit(1);
+    }
+}
+
+fn parse_day(day: &str) -> usize {
+    match day.parse() {
+        Ok(day) if (1..=DAYS.len()).contains(&day) => day,
+        _ => usage(),
+    }
+}
+
+/// the directory of each day's crate, where its inputs and answers are checked in
+fn day_dir(day: usize) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the aoc crate is inside the workspace")
+        .join(format!("day-{:02}", day))
+}
+
+fn default_input(day: usize) -> Source {
+    Source::File(day_dir(day).join("input.txt"))
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
@@ -0,0 +1,65 @@
+use crate::{day_dir, Solver};
+use common::answers::{Answers, Expected};
+use common::unwind::catch_panic;
+use std::fs;
+
+/// check each of a day's stored answers, returning the number passed and failed
+pub fn verify_day(day: usize, solver: Solver) -> (usize, usize) {
+    let answers_filename = day_dir(day).join("answers.txt");
+
+    let answers: Answers = match fs::read_to_string(&answers_filename) {
+        Ok(text) => match text.parse() {
+            Ok(answers) => answers,
+            Err(error) => {
+                println!(
+                    "FAIL day {:02}: answers.txt line {} is malformed",
+                    day, error.line
+                );
+                return (0, 1);
+            }
+        },
+        Err(error) => {
+            println!("FAIL day {:02}: could not read answers.txt: {}", day, error);
+            return (0, 1);
+        }
+    };
+
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for expected in &answers.0 {
+        let label = format!("day {:02} part {} {}", day, expected.part, expected.input);
+
+        match check(day, solver, expected) {
+            Ok(answer) => {
+                println!("PASS {}: {}", label, answer);
+                passed += 1;
+            }
+            Err(reason) => {
+                println!("FAIL {}: {}", label, reason);
+                failed += 1;
+            }
+        }
+    }
+
+    (passed, failed)
+}
+
+fn check(day: usize, solver: Solver, expected: &Expected) -> Result<String, String> {
+    let input = fs::read_to_string(day_dir(day).join(&expected.input))
+        .map_err(|error| format!("could not read input: {}", error))?;
+
+    let answers = catch_panic(|| solver(&input, Some(expected.part)))
+        .map_err(|message| format!("panicked: {}", message))?
+        .map_err(|error| format!("parse error: {}", error))?;
+    let answer = answers
+        .first()
+        .map(|solved| solved.answer.to_string())
+        .ok_or_else(|| String::from("no answer"))?;
+
+    if answer == expected.answer {
+        Ok(answer)
+    } else {
+        Err(format!("expected {}, got {}", expected.answer, answer))
+    }
+}
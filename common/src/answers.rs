@@ -0,0 +1,104 @@
+// Each day keeps an `answers.txt` next to its inputs, one expected answer per line:
+//
+//     # input part answer
+//     test-input.txt 1 142
+//     input.txt 2 54591
+
+use crate::solution::Part;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Expected {
+    pub input: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseAnswersError {
+    pub line: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Answers(pub Vec<Expected>);
+
+impl FromStr for Answers {
+    type Err = ParseAnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut expected = vec![];
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = || ParseAnswersError { line: i + 1 };
+            let mut fields = line.split_whitespace();
+
+            let input = fields.next().ok_or_else(error)?.to_owned();
+            let part = fields
+                .next()
+                .and_then(|part| part.parse().ok())
+                .ok_or_else(error)?;
+            let answer = fields.next().ok_or_else(error)?.to_owned();
+
+            if fields.next().is_some() {
+                return Err(error());
+            }
+
+            expected.push(Expected {
+                input,
+                part,
+                answer,
+            });
+        }
+
+        Ok(Answers(expected))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::answers::{Answers, Expected, ParseAnswersError};
+    use crate::solution::Part;
+
+    #[test]
+    fn test_parse() {
+        let text = "# input part answer\ntest-input.txt 1 142\n\ninput.txt 2 -5\n";
+        let answers: Answers = text.parse().unwrap();
+
+        assert_eq!(
+            answers.0,
+            vec![
+                Expected {
+                    input: String::from("test-input.txt"),
+                    part: Part::One,
+                    answer: String::from("142"),
+                },
+                Expected {
+                    input: String::from("input.txt"),
+                    part: Part::Two,
+                    answer: String::from("-5"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "input.txt 3 1".parse::<Answers>(),
+            Err(ParseAnswersError { line: 1 })
+        );
+        assert_eq!(
+            "input.txt 1 1\ninput.txt 2".parse::<Answers>(),
+            Err(ParseAnswersError { line: 2 })
+        );
+        assert_eq!(
+            "input.txt 1 1 1".parse::<Answers>(),
+            Err(ParseAnswersError { line: 1 })
+        );
+    }
+}
@@ -0,0 +1,125 @@
+// Timing a day's phases separately, so a slow parse can't hide behind a fast part or
+// the other way round. Each iteration parses the input afresh and then solves both
+// parts from that parse.
+
+use crate::error::ParseError;
+use crate::solution::Solution;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How long each phase took on every iteration.
+#[derive(Debug, Default)]
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub part_one: Vec<Duration>,
+    pub part_two: Vec<Duration>,
+}
+
+/// The spread of a phase's timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// None if there are no timings to summarize
+    pub fn of(timings: &[Duration]) -> Option<Self> {
+        let mut sorted = timings.to_vec();
+        sorted.sort();
+
+        let median = match sorted.len() {
+            0 => return None,
+            n if n % 2 == 0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+            n => sorted[n / 2],
+        };
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        })
+    }
+}
+
+/// time parsing and each part over the given number of iterations
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<Timings, ParseError> {
+    let mut timings = Timings::default();
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input))?;
+        timings.parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part_one(&parsed));
+        timings.part_one.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part_two(&parsed));
+        timings.part_two.push(start.elapsed());
+    }
+
+    Ok(timings)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::bench::{bench, Stats};
+    use crate::error::ParseError;
+    use crate::solution::{Answer, Solution};
+    use std::time::Duration;
+
+    struct Length;
+
+    impl Solution for Length {
+        type Input = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            match input.len() {
+                0 => Err(ParseError::new(1, 1, "expected some puzzle input")),
+                n => Ok(n),
+            }
+        }
+
+        fn part_one(input: &Self::Input) -> Answer {
+            (*input).into()
+        }
+
+        fn part_two(input: &Self::Input) -> Answer {
+            (input * 2).into()
+        }
+    }
+
+    #[test]
+    fn test_bench() {
+        let timings = bench::<Length>("abc", 5).unwrap();
+        assert_eq!(timings.parse.len(), 5);
+        assert_eq!(timings.part_one.len(), 5);
+        assert_eq!(timings.part_two.len(), 5);
+
+        assert!(bench::<Length>("", 5).is_err());
+    }
+
+    #[test]
+    fn test_stats() {
+        let millis = |ms: &[u64]| -> Vec<Duration> {
+            ms.iter().map(|&ms| Duration::from_millis(ms)).collect()
+        };
+
+        assert_eq!(
+            Stats::of(&millis(&[5, 1, 3])),
+            Some(Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                max: Duration::from_millis(5)
+            })
+        );
+        assert_eq!(
+            Stats::of(&millis(&[4, 1, 2, 9])).map(|stats| stats.median),
+            Some(Duration::from_millis(3))
+        );
+        assert_eq!(Stats::of(&[]), None);
+    }
+}
@@ -0,0 +1,136 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A parse error from somewhere in a puzzle input, with a 1-based line and column.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// place an error from a piece of the input that starts on the given line
+    pub fn at_line(line: usize, error: &impl Located) -> Self {
+        Self::new(line + error.line() - 1, error.column(), error.to_string())
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Errors from parsing a piece of the input which know where in that piece they happened.
+pub trait Located: fmt::Display {
+    /// the line within the piece being parsed, for pieces spanning several lines
+    fn line(&self) -> usize {
+        1
+    }
+
+    fn column(&self) -> usize;
+}
+
+/// the 1-based column at which `part`, which must be a slice of `line`, starts
+pub fn column_of(line: &str, part: &str) -> usize {
+    part.as_ptr() as usize - line.as_ptr() as usize + 1
+}
+
+/// parse each line of the input, reporting the first line that fails
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Located,
+{
+    if input.trim().is_empty() {
+        return Err(ParseError::new(1, 1, "expected some puzzle input"));
+    }
+
+    input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse()
+                .map_err(|error| ParseError::at_line(i + 1, &error))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::error::{column_of, parse_lines, Located, ParseError};
+    use std::fmt;
+    use std::str::FromStr;
+
+    #[derive(Debug)]
+    struct Number(usize);
+
+    struct ParseNumberError {
+        column: usize,
+    }
+
+    impl fmt::Display for ParseNumberError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "expected a digit")
+        }
+    }
+
+    impl Located for ParseNumberError {
+        fn column(&self) -> usize {
+            self.column
+        }
+    }
+
+    impl FromStr for Number {
+        type Err = ParseNumberError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.find(|c: char| !c.is_ascii_digit()) {
+                Some(i) => Err(ParseNumberError { column: i + 1 }),
+                None => Ok(Number(s.parse().unwrap())),
+            }
+        }
+    }
+
+    #[test]
+    fn test_column_of() {
+        let line = "Game 12: 3 blue";
+        assert_eq!(column_of(line, &line[5..7]), 6);
+        assert_eq!(column_of(line, line), 1);
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let numbers: Vec<Number> = parse_lines("12\n34\n").unwrap();
+        assert_eq!(
+            numbers.iter().map(|n| n.0).collect::<Vec<_>>(),
+            vec![12, 34]
+        );
+
+        assert_eq!(
+            parse_lines::<Number>("12\n3x4").unwrap_err(),
+            ParseError::new(2, 2, "expected a digit")
+        );
+        assert_eq!(
+            parse_lines::<Number>(" \n").unwrap_err().to_string(),
+            "line 1, column 1: expected some puzzle input"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+// Random puzzle inputs, for stress testing the solvers well beyond the size of the
+// real inputs. The same seed and size always produce the same input.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// A day's random input generator, shaped by its own parameters.
+pub trait Generator {
+    type Params;
+
+    /// parameters scaled from a single size, roughly the number of lines or rows
+    fn params(size: usize) -> Self::Params;
+    fn generate(rng: &mut StdRng, params: &Self::Params) -> String;
+}
+
+/// generate an input of the given size from the given seed
+pub fn generate<G: Generator>(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    G::generate(&mut rng, &G::params(size))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::generator::{generate, Generator};
+    use rand::rngs::StdRng;
+    use rand::Rng;
+
+    struct Numbers;
+
+    impl Generator for Numbers {
+        type Params = usize;
+
+        fn params(size: usize) -> Self::Params {
+            size
+        }
+
+        fn generate(rng: &mut StdRng, lines: &Self::Params) -> String {
+            (0..*lines)
+                .map(|_| format!("{}\n", rng.gen_range(0..1000)))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate::<Numbers>(7, 5), generate::<Numbers>(7, 5));
+        assert_ne!(generate::<Numbers>(7, 5), generate::<Numbers>(8, 5));
+        assert_eq!(generate::<Numbers>(7, 5).lines().count(), 5);
+    }
+}
@@ -0,0 +1,191 @@
+// Puzzle inputs named on the command line, either as file paths or as `-` for stdin.
+
+use crate::error::ParseError;
+use crate::report::Format;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::File(path) => fs::read_to_string(path),
+        }
+    }
+
+    /// a buffered reader over the input, for inputs too big to read all at once
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => Ok(Box::new(BufReader::new(fs::File::open(path)?))),
+        }
+    }
+}
+
+impl From<&str> for Source {
+    fn from(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NoSourcesError;
+
+impl fmt::Display for NoSourcesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected an input file, or - to read from stdin")
+    }
+}
+
+/// the sources named by the arguments, of which there must be at least one so that
+/// waiting on stdin is always asked for
+pub fn sources<S: AsRef<str>>(
+    args: impl IntoIterator<Item = S>,
+) -> Result<Vec<Source>, NoSourcesError> {
+    let sources: Vec<Source> = args
+        .into_iter()
+        .map(|arg| Source::from(arg.as_ref()))
+        .collect();
+
+    if sources.is_empty() {
+        Err(NoSourcesError)
+    } else {
+        Ok(sources)
+    }
+}
+
+/// Whether to head each input's results with its name when there are several.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Headers {
+    Shown,
+    /// for output that must stay in one format, like JSON or an export
+    Hidden,
+}
+
+impl From<Format> for Headers {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Text => Headers::Shown,
+            Format::Json => Headers::Hidden,
+        }
+    }
+}
+
+/// read and handle each input in turn, heading each block of text results with the
+/// source's name when there are several, and reporting any input that can't be
+/// read or parsed without stopping; returns whether every input succeeded
+pub fn for_each(
+    sources: &[Source],
+    headers: impl Into<Headers>,
+    mut f: impl FnMut(&Source, &str) -> Result<(), ParseError>,
+) -> bool {
+    for_each_reader(sources, headers, |source, reader| {
+        let mut input = String::new();
+        reader
+            .read_to_string(&mut input)
+            .map_err(|error| error.to_string())?;
+        f(source, &input).map_err(|error| error.to_string())
+    })
+}
+
+/// like `for_each`, but handing each input over as a reader rather than reading it first
+pub fn for_each_reader<E: fmt::Display>(
+    sources: &[Source],
+    headers: impl Into<Headers>,
+    mut f: impl FnMut(&Source, &mut dyn BufRead) -> Result<(), E>,
+) -> bool {
+    let headers = headers.into();
+    let mut succeeded = true;
+
+    for (i, source) in sources.iter().enumerate() {
+        if headers == Headers::Shown && sources.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+            println!("==> {} <==", source);
+        }
+
+        let result = source
+            .open()
+            .map_err(|error| error.to_string())
+            .and_then(|mut reader| f(source, &mut reader).map_err(|error| error.to_string()));
+
+        if let Err(error) = result {
+            eprintln!("{}: {}", source, error);
+            succeeded = false;
+        }
+    }
+
+    succeeded
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::error::ParseError;
+    use crate::input::{for_each, sources, Headers, NoSourcesError, Source};
+    use crate::report::Format;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_sources() {
+        assert_eq!(
+            sources(["test-input.txt", "-"]),
+            Ok(vec![
+                Source::File(PathBuf::from("test-input.txt")),
+                Source::Stdin
+            ])
+        );
+        assert_eq!(sources(Vec::<String>::new()), Err(NoSourcesError));
+        assert_eq!(Source::Stdin.to_string(), "<stdin>");
+    }
+
+    #[test]
+    fn test_for_each() {
+        let manifest = Source::File(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"));
+        let missing = Source::File(PathBuf::from("no-such-input.txt"));
+        let mut seen = 0;
+
+        let succeeded = for_each(&[manifest.clone(), missing], Format::Text, |_, input| {
+            assert!(input.contains("[package]"));
+            seen += 1;
+            Ok(())
+        });
+        assert!(!succeeded);
+        assert_eq!(seen, 1);
+
+        let succeeded = for_each(std::slice::from_ref(&manifest), Format::Json, |_, _| {
+            Err(ParseError::new(1, 1, "no"))
+        });
+        assert!(!succeeded);
+
+        assert!(for_each(
+            &[manifest.clone(), manifest],
+            Headers::Hidden,
+            |_, _| Ok(())
+        ));
+    }
+}
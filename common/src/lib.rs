@@ -0,0 +1,9 @@
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod generator;
+pub mod input;
+pub mod reference;
+pub mod report;
+pub mod solution;
+pub mod unwind;
@@ -0,0 +1,163 @@
+// Slow but obviously correct solutions to check a day's clever ones against, on
+// generated inputs small enough for the slow ones to finish.
+
+use crate::generator::Generator;
+use crate::solution::{Answer, Part, Solution};
+use crate::unwind::catch_panic;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::fmt;
+
+pub trait Reference: Solution + Generator {
+    /// generator parameters small enough for the reference solutions to finish quickly
+    fn reference_params(size: usize) -> Self::Params;
+
+    /// the reference answer to a part, or None if that part has no shortcut to check
+    fn reference(part: Part, input: &Self::Input) -> Option<Answer>;
+}
+
+/// A generated input on which the fast and reference solutions differ.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub seed: u64,
+    pub size: usize,
+    /// None if the input couldn't be parsed at all
+    pub part: Option<Part>,
+    /// the fast solution's answer, or why there isn't one
+    pub fast: String,
+    pub reference: String,
+    pub input: String,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let part = self
+            .part
+            .map_or(String::from("parse"), |part| format!("part {}", part));
+
+        writeln!(
+            f,
+            "seed {} size {} {}: fast {}, reference {}",
+            self.seed, self.size, part, self.fast, self.reference
+        )?;
+        write!(f, "{}", self.input)
+    }
+}
+
+/// compare the fast and reference solutions on an input generated from each seed in
+/// turn, with sizes cycling up from 1 so that small inputs are tried first, and return
+/// the first input they disagree on
+pub fn differential<R: Reference>(cases: u64, max_size: usize) -> Option<Disagreement> {
+    (0..cases).find_map(|seed| {
+        let size = 1 + seed as usize % max_size.max(1);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let input = R::generate(&mut rng, &R::reference_params(size));
+
+        let disagreement = |part, fast, reference| Disagreement {
+            seed,
+            size,
+            part,
+            fast,
+            reference,
+            input: input.clone(),
+        };
+
+        let parsed = match catch_panic(|| R::parse(&input)) {
+            Ok(Ok(parsed)) => parsed,
+            Ok(Err(error)) => {
+                let fast = format!("parse error: {}", error);
+                return Some(disagreement(None, fast, String::from("no error")));
+            }
+            Err(message) => {
+                let fast = format!("panicked: {}", message);
+                return Some(disagreement(None, fast, String::from("no panic")));
+            }
+        };
+
+        [Part::One, Part::Two].into_iter().find_map(|part| {
+            let reference = match catch_panic(|| R::reference(part, &parsed)) {
+                Ok(Some(answer)) => answer.to_string(),
+                Ok(None) => return None,
+                Err(message) => format!("panicked: {}", message),
+            };
+
+            let fast = match catch_panic(|| match part {
+                Part::One => R::part_one(&parsed),
+                Part::Two => R::part_two(&parsed),
+            }) {
+                Ok(answer) => answer.to_string(),
+                Err(message) => format!("panicked: {}", message),
+            };
+
+            (fast != reference).then(|| disagreement(Some(part), fast, reference))
+        })
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::error::ParseError;
+    use crate::generator::Generator;
+    use crate::reference::{differential, Reference};
+    use crate::solution::{Answer, Part, Solution};
+    use rand::rngs::StdRng;
+    use rand::Rng;
+
+    /// sums numbers, but part two thinks every number over 90 is 90
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<usize>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.lines().map(|line| line.parse().unwrap()).collect())
+        }
+
+        fn part_one(numbers: &Self::Input) -> Answer {
+            numbers.iter().sum::<usize>().into()
+        }
+
+        fn part_two(numbers: &Self::Input) -> Answer {
+            numbers.iter().map(|&n| n.min(90)).sum::<usize>().into()
+        }
+    }
+
+    impl Generator for Sum {
+        type Params = usize;
+
+        fn params(size: usize) -> Self::Params {
+            size
+        }
+
+        fn generate(rng: &mut StdRng, lines: &Self::Params) -> String {
+            (0..*lines)
+                .map(|_| format!("{}\n", rng.gen_range(0..100)))
+                .collect()
+        }
+    }
+
+    impl Reference for Sum {
+        fn reference_params(size: usize) -> Self::Params {
+            size
+        }
+
+        fn reference(part: Part, numbers: &Self::Input) -> Option<Answer> {
+            match part {
+                Part::One => None,
+                Part::Two => Some(numbers.iter().sum::<usize>().into()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_differential() {
+        let disagreement = differential::<Sum>(100, 5).unwrap();
+
+        assert_eq!(disagreement.part, Some(Part::Two));
+        assert!(disagreement
+            .input
+            .lines()
+            .any(|line| line.parse::<usize>().unwrap() > 90));
+        assert!(differential::<Sum>(disagreement.seed, 5).is_none());
+    }
+}
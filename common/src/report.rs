@@ -0,0 +1,188 @@
+// Answers as machine-readable records, one JSON object per line and part:
+//
+//   {"day":3,"part":1,"answer":4361,"input":"test-input.txt","elapsed":0.000021}
+//
+// where elapsed is the seconds taken to solve that part, not counting parsing, and
+// answer is null for a part the input has no answer to.
+
+use crate::error::ParseError;
+use crate::input::Source;
+use crate::solution::{solve, Answer, Part, Solution, Solved};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseFormatError(String);
+
+impl fmt::Display for ParseFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected --format text or json, got {:?}", self.0)
+    }
+}
+
+impl FromStr for Format {
+    type Err = ParseFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(ParseFormatError(s.to_string())),
+        }
+    }
+}
+
+/// remove a `--format <text|json>` option from the arguments, defaulting to text
+pub fn take_format(args: &mut Vec<String>) -> Result<Format, ParseFormatError> {
+    match args.iter().position(|arg| arg == "--format") {
+        Some(i) if i + 1 < args.len() => {
+            let format = args[i + 1].parse();
+            args.drain(i..=i + 1);
+            format
+        }
+        Some(_) => Err(ParseFormatError(String::new())),
+        None => Ok(Format::Text),
+    }
+}
+
+pub struct Record<'a> {
+    pub day: usize,
+    pub input: &'a Source,
+    pub solved: &'a Solved,
+}
+
+impl fmt::Display for Record<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let answer = match self.solved.answer {
+            Answer::Unanswerable => String::from("null"),
+            answer => answer.to_string(),
+        };
+
+        write!(
+            f,
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"input\":{},\"elapsed\":{}}}",
+            self.day,
+            self.solved.part,
+            answer,
+            json_string(&self.input.to_string()),
+            self.solved.elapsed.as_secs_f64()
+        )
+    }
+}
+
+/// print a record for each part solved from the input, or both parts if none is given
+pub fn print_records<S: Solution>(
+    day: usize,
+    input: &Source,
+    text: &str,
+    part: Option<Part>,
+) -> Result<(), ParseError> {
+    for solved in solve::<S>(text, part)? {
+        println!(
+            "{}",
+            Record {
+                day,
+                input,
+                solved: &solved,
+            }
+        );
+    }
+    Ok(())
+}
+
+/// a JSON string literal with the given contents
+pub fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::input::Source;
+    use crate::report::{json_string, take_format, Format, ParseFormatError, Record};
+    use crate::solution::{Answer, Part, Solved};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_take_format() {
+        let mut with_format = args(&["a.txt", "--format", "json", "b.txt"]);
+        assert_eq!(take_format(&mut with_format), Ok(Format::Json));
+        assert_eq!(with_format, args(&["a.txt", "b.txt"]));
+
+        let mut without_format = args(&["a.txt"]);
+        assert_eq!(take_format(&mut without_format), Ok(Format::Text));
+        assert_eq!(without_format, args(&["a.txt"]));
+
+        assert_eq!(
+            take_format(&mut args(&["--format", "xml"])),
+            Err(ParseFormatError(String::from("xml")))
+        );
+        assert!(take_format(&mut args(&["--format"])).is_err());
+    }
+
+    #[test]
+    fn test_record() {
+        let input = Source::File(PathBuf::from("test-input.txt"));
+        let solved = Solved {
+            part: Part::Two,
+            answer: Answer::Signed(-2),
+            elapsed: Duration::from_millis(1500),
+        };
+
+        assert_eq!(
+            Record {
+                day: 9,
+                input: &input,
+                solved: &solved
+            }
+            .to_string(),
+            r#"{"day":9,"part":2,"answer":-2,"input":"test-input.txt","elapsed":1.5}"#
+        );
+
+        let unanswerable = Solved {
+            answer: Answer::Unanswerable,
+            ..solved
+        };
+        assert_eq!(
+            Record {
+                day: 8,
+                input: &input,
+                solved: &unanswerable
+            }
+            .to_string(),
+            r#"{"day":8,"part":2,"answer":null,"input":"test-input.txt","elapsed":1.5}"#
+        );
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a \"b\"\\c\n"), r#""a \"b\"\\c\n""#);
+        assert_eq!(json_string("\u{1}"), r#""\u0001""#);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::error::ParseError;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsePartError;
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(ParsePartError),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    /// the input is fine, but has nothing for this part to answer, like a network for the
+    /// Day 8 ghosts without the AAA that part one starts from, or only an answer too large
+    /// to hold
+    Unanswerable,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unanswerable => write!(f, "no answer"),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Unsigned(n as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::Unsigned(n)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Answer::Signed(n as i64)
+    }
+}
+
+/// A day's puzzle: the input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Answer;
+    fn part_two(input: &Self::Input) -> Answer;
+}
+
+/// The answer to one part, with how long that part took to solve once the input was parsed.
+#[derive(Debug, Clone, Copy)]
+pub struct Solved {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// parse the input and solve the given part, or both parts if none is given
+pub fn solve<S: Solution>(input: &str, part: Option<Part>) -> Result<Vec<Solved>, ParseError> {
+    let parsed = S::parse(input)?;
+    let mut answers = vec![];
+
+    let timed = |part, solve_part: fn(&S::Input) -> Answer| {
+        let start = Instant::now();
+        let answer = solve_part(&parsed);
+        Solved {
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        }
+    };
+
+    if part != Some(Part::Two) {
+        answers.push(timed(Part::One, S::part_one));
+    }
+    if part != Some(Part::One) {
+        answers.push(timed(Part::Two, S::part_two));
+    }
+
+    Ok(answers)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{solve, Answer, Part, Solution};
+    use crate::error::ParseError;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input = Vec<usize>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.lines().map(str::len).collect())
+        }
+
+        fn part_one(input: &Self::Input) -> Answer {
+            input.len().into()
+        }
+
+        fn part_two(input: &Self::Input) -> Answer {
+            input.iter().sum::<usize>().into()
+        }
+    }
+
+    fn answers(input: &str, part: Option<Part>) -> Vec<(Part, Answer)> {
+        solve::<Lines>(input, part)
+            .unwrap()
+            .into_iter()
+            .map(|solved| (solved.part, solved.answer))
+            .collect()
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            answers("ab\ncde", None),
+            vec![
+                (Part::One, Answer::Unsigned(2)),
+                (Part::Two, Answer::Unsigned(5))
+            ]
+        );
+        assert_eq!(
+            answers("ab\ncde", Some(Part::Two)),
+            vec![(Part::Two, Answer::Unsigned(5))]
+        );
+    }
+
+    #[test]
+    fn test_display_answer() {
+        assert_eq!(Answer::from(42usize).to_string(), "42");
+        assert_eq!(Answer::from(-3isize).to_string(), "-3");
+        assert_eq!(Answer::Unanswerable.to_string(), "no answer");
+    }
+}
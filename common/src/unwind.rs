@@ -0,0 +1,71 @@
+// Running a solver that might panic without the panic ending the whole run, so that
+// commands checking many inputs can report it like any other failure.
+
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+static SILENCE_CAUGHT_PANICS: Once = Once::new();
+
+thread_local! {
+    /// whether this thread is inside catch_panic, so its panics aren't printed
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// the result of f, or the message it panicked with; the panic hook is wrapped once for
+/// the whole process, rather than swapped out around each call, so panics on other
+/// threads, like tests running alongside, are still printed
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    SILENCE_CAUGHT_PANICS.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.with(Cell::get) {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let outer = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(outer));
+
+    result.map_err(|payload| panic_message(&payload).to_string())
+}
+
+fn panic_message(payload: &Box<dyn Any + Send>) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown cause"
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::unwind::catch_panic;
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+        assert_eq!(
+            catch_panic(|| -> usize { panic!("no answer") }),
+            Err(String::from("no answer"))
+        );
+        assert_eq!(
+            catch_panic(|| -> usize { panic!("{} answers", 2) }),
+            Err(String::from("2 answers"))
+        );
+
+        // nested calls leave the outer one still catching
+        assert_eq!(
+            catch_panic(|| {
+                let inner = catch_panic(|| -> usize { panic!("inner") });
+                panic!("outer after {:?}", inner)
+            }),
+            Err(String::from("outer after Err(\"inner\")"))
+        );
+    }
+}
@@ -0,0 +1,62 @@
+// Throughput of finding calibration tokens in multi-megabyte generated documents,
+// for the automaton scanner and the naive scan it replaced.
+// Usage: `cargo bench -p day-01 [-- <megabytes>...]
+
+use common::bench::Stats;
+use common::generator::generate;
+use day_01::dictionary::Dictionary;
+use day_01::scanner::{naive_tokens, Scanner};
+use day_01::Day01;
+use std::env;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: usize = 10;
+
+/// generated lines average about 14 bytes
+const LINES_PER_MEGABYTE: usize = 1_000_000 / 14;
+
+fn main() {
+    let megabytes: Vec<usize> = env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse().ok())
+        .collect();
+    let megabytes = if megabytes.is_empty() {
+        vec![1, 4, 16]
+    } else {
+        megabytes
+    };
+
+    let dictionary = Dictionary::english();
+    let scanner = Scanner::new(&dictionary);
+
+    for size in megabytes {
+        let document = generate::<Day01>(0, size * LINES_PER_MEGABYTE);
+
+        println!("{:.1} MB document", document.len() as f64 / 1e6);
+        report("scanner", &document, |line| scanner.tokens(line).len());
+        report("naive", &document, |line| {
+            naive_tokens(line, &dictionary).len()
+        });
+    }
+}
+
+/// time counting the tokens in every line of the document
+fn report(name: &str, document: &str, count: impl Fn(&str) -> usize) {
+    let timings: Vec<Duration> = (0..ITERATIONS)
+        .map(|_| {
+            let start = Instant::now();
+            let tokens: usize = black_box(document).lines().map(&count).sum();
+            black_box(tokens);
+            start.elapsed()
+        })
+        .collect();
+
+    let stats = Stats::of(&timings).expect("there are timings");
+    println!(
+        "  {:<8} median {:>10.1?}  {:>8.1} MB/s",
+        name,
+        stats.median,
+        document.len() as f64 / 1e6 / stats.median.as_secs_f64()
+    );
+}
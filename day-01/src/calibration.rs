@@ -0,0 +1,145 @@
+use crate::dictionary::Dictionary;
+use crate::policy::{Policy, ValueError};
+use crate::scanner::Scanner;
+use common::error::ParseError;
+use std::io::{self, BufRead};
+
+/// the sum of the first and last digits of every line, where lines without any digits
+/// add nothing, just as when reading the lines one at a time
+pub fn sum_of_calibration_values(input: &str, dictionary: &Dictionary) -> usize {
+    read_sum_of_calibration_values(input.as_bytes(), dictionary, Policy::FirstLast)
+        .expect("values of two digits from text in memory can't overflow their sum")
+}
+
+/// the sum over every line of the reader, holding only one line in memory at a time;
+/// lines may end in \n or \r\n, the last one may not end at all, and blank lines
+/// and lines without any digits add nothing
+pub fn read_sum_of_calibration_values(
+    mut reader: impl BufRead,
+    dictionary: &Dictionary,
+    policy: Policy,
+) -> io::Result<usize> {
+    let scanner = Scanner::new(dictionary);
+    let mut buffer = String::new();
+    let mut sum: usize = 0;
+
+    for number in 1.. {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            break;
+        }
+
+        let line = buffer.strip_suffix('\n').unwrap_or(&buffer);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        let value = match policy.value(&scanner.tokens(line)) {
+            Err(ValueError::NoDigits) => continue,
+            value => value,
+        };
+        sum = value
+            .and_then(|value| sum.checked_add(value).ok_or(ValueError::TooLarge))
+            .map_err(|error| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    ParseError::at_line(number, &error),
+                )
+            })?;
+    }
+
+    Ok(sum)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::calibration::{read_sum_of_calibration_values, sum_of_calibration_values};
+    use crate::dictionary::Dictionary;
+    use crate::policy::Policy;
+    use std::io::BufReader;
+
+    #[test]
+    fn test_sum_of_calibration_values() {
+        let input = "two1nine\nabcone2threexyz\nzero7\n";
+
+        assert_eq!(
+            sum_of_calibration_values(input, &Dictionary::english()),
+            29 + 13 + 77
+        );
+        assert_eq!(
+            sum_of_calibration_values(input, &Dictionary::language("english", true).unwrap()),
+            29 + 13 + 7
+        );
+        assert_eq!(
+            sum_of_calibration_values("eightwothree", &Dictionary::english()),
+            83
+        );
+        assert_eq!(
+            sum_of_calibration_values(input, &Dictionary::digits_only()),
+            11 + 22 + 77
+        );
+        assert_eq!(
+            sum_of_calibration_values("two1nine\neightwothree\n", &Dictionary::digits_only()),
+            11
+        );
+    }
+
+    #[test]
+    fn test_other_languages() {
+        assert_eq!(
+            sum_of_calibration_values("fünfxdreizehn\n", &Dictionary::german()),
+            53
+        );
+        assert_eq!(
+            sum_of_calibration_values("zérouxdeux\n", &Dictionary::french()),
+            22
+        );
+        assert_eq!(
+            sum_of_calibration_values(
+                "zérouxdeux\n",
+                &Dictionary::language("french", true).unwrap()
+            ),
+            2
+        );
+    }
+
+    #[test]
+    fn test_read_sum_of_calibration_values() {
+        let english = Dictionary::english();
+        let read = |input: &str| {
+            // a tiny buffer so lines span several fills
+            read_sum_of_calibration_values(
+                BufReader::with_capacity(3, input.as_bytes()),
+                &english,
+                Policy::FirstLast,
+            )
+        };
+
+        assert_eq!(read("two1nine\r\nabcone2threexyz\r\n").unwrap(), 29 + 13);
+        assert_eq!(read("two1nine\n\nzero7").unwrap(), 29 + 77);
+        assert_eq!(read("").unwrap(), 0);
+        assert_eq!(read("1\r\nabc\r\n").unwrap(), 11);
+    }
+
+    #[test]
+    fn test_policies() {
+        let input = "two1nine\nabcone2threexyz\n";
+        let read = |policy| {
+            read_sum_of_calibration_values(input.as_bytes(), &Dictionary::english(), policy)
+                .unwrap()
+        };
+
+        assert_eq!(read(Policy::Concatenated), 219 + 123);
+        assert_eq!(read(Policy::Sum), 12 + 6);
+        assert_eq!(read(Policy::Last(2)), 19 + 23);
+
+        let error = read_sum_of_calibration_values(
+            "1\n99999999999999999999\n".as_bytes(),
+            &Dictionary::digits_only(),
+            Policy::Concatenated,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: the value is too large"
+        );
+    }
+}
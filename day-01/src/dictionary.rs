@@ -0,0 +1,235 @@
+// Words that spell out digits in a calibration document. The built in languages spell
+// out one to nine, as the puzzle does, and zero too only when asked. Besides them, a
+// dictionary can be read from a file with one word and its digit per line, like
+// `null 0`, where blank lines and lines starting with # are ignored.
+
+use common::error::{column_of, Located};
+use std::fmt;
+use std::str::FromStr;
+use ParseDictionaryError::*;
+
+pub const LANGUAGES: [&str; 4] = ["english", "german", "spanish", "french"];
+
+/// the words for zero to nine in each of the `LANGUAGES`
+const WORDS: [[&str; 10]; 4] = [
+    [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ],
+    [
+        "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+    ],
+    [
+        "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+    ],
+    [
+        "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+    ],
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dictionary {
+    words: Vec<(String, usize)>,
+}
+
+impl Dictionary {
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = (S, usize)>) -> Self {
+        Self {
+            words: words
+                .into_iter()
+                .map(|(word, digit)| (word.into(), digit))
+                .collect(),
+        }
+    }
+
+    /// no words at all, so only the digits themselves count
+    pub fn digits_only() -> Self {
+        Self::new(Vec::<(String, usize)>::new())
+    }
+
+    /// one to nine, which is what the puzzle spells out
+    pub fn english() -> Self {
+        Self::numbered(WORDS[0], false)
+    }
+
+    pub fn german() -> Self {
+        Self::numbered(WORDS[1], false)
+    }
+
+    pub fn spanish() -> Self {
+        Self::numbered(WORDS[2], false)
+    }
+
+    pub fn french() -> Self {
+        Self::numbered(WORDS[3], false)
+    }
+
+    /// one of the built in `LANGUAGES`, from one to nine or from zero
+    pub fn language(name: &str, zero: bool) -> Option<Self> {
+        LANGUAGES
+            .iter()
+            .position(|&language| language == name)
+            .map(|i| Self::numbered(WORDS[i], zero))
+    }
+
+    /// the words in order, starting from the word for zero or for one
+    fn numbered(words: [&str; 10], zero: bool) -> Self {
+        let first = if zero { 0 } else { 1 };
+        Self::new(words.into_iter().zip(0..).skip(first))
+    }
+
+    pub fn words(&self) -> &[(String, usize)] {
+        &self.words
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseDictionaryError {
+    MissingDigit { line: usize, column: usize },
+    InvalidDigit { line: usize, column: usize },
+    DigitInWord { line: usize, column: usize },
+    UnexpectedText { line: usize, column: usize },
+}
+
+impl fmt::Display for ParseDictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MissingDigit { .. } => write!(f, "expected a digit after the word"),
+            InvalidDigit { .. } => write!(f, "expected a single digit from 0 to 9"),
+            DigitInWord { .. } => write!(f, "expected a word without any digits in it"),
+            UnexpectedText { .. } => write!(f, "expected nothing after the digit"),
+        }
+    }
+}
+
+impl Located for ParseDictionaryError {
+    fn line(&self) -> usize {
+        match self {
+            MissingDigit { line, .. }
+            | InvalidDigit { line, .. }
+            | DigitInWord { line, .. }
+            | UnexpectedText { line, .. } => *line,
+        }
+    }
+
+    fn column(&self) -> usize {
+        match self {
+            MissingDigit { column, .. }
+            | InvalidDigit { column, .. }
+            | DigitInWord { column, .. }
+            | UnexpectedText { column, .. } => *column,
+        }
+    }
+}
+
+impl FromStr for Dictionary {
+    type Err = ParseDictionaryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = vec![];
+
+        for (i, line) in s.lines().enumerate() {
+            let line_number = i + 1;
+            let mut fields = line.split_whitespace();
+
+            let word = match fields.next() {
+                None => continue,
+                Some(word) if word.starts_with('#') => continue,
+                Some(word) => word,
+            };
+
+            if let Some(position) = word.find(|c: char| c.is_ascii_digit()) {
+                return Err(DigitInWord {
+                    line: line_number,
+                    column: column_of(line, word) + position,
+                });
+            }
+
+            let digit = fields.next().ok_or(MissingDigit {
+                line: line_number,
+                column: line.trim_end().len() + 1,
+            })?;
+
+            let digit = match digit.as_bytes() {
+                [d] if d.is_ascii_digit() => (d - b'0') as usize,
+                _ => {
+                    return Err(InvalidDigit {
+                        line: line_number,
+                        column: column_of(line, digit),
+                    })
+                }
+            };
+
+            if let Some(extra) = fields.next() {
+                return Err(UnexpectedText {
+                    line: line_number,
+                    column: column_of(line, extra),
+                });
+            }
+
+            words.push((word, digit));
+        }
+
+        Ok(Dictionary::new(words))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::dictionary::{Dictionary, ParseDictionaryError::*, LANGUAGES};
+
+    #[test]
+    fn test_languages() {
+        for name in LANGUAGES {
+            let digits = |zero| -> Vec<usize> {
+                let dictionary = Dictionary::language(name, zero).unwrap();
+                dictionary.words().iter().map(|&(_, d)| d).collect()
+            };
+            assert_eq!(digits(false), (1..10).collect::<Vec<_>>());
+            assert_eq!(digits(true), (0..10).collect::<Vec<_>>());
+        }
+
+        assert_eq!(Dictionary::english().words()[0], (String::from("one"), 1));
+        assert_eq!(
+            Dictionary::language("french", false),
+            Some(Dictionary::french())
+        );
+        assert_eq!(Dictionary::language("klingon", true), None);
+        assert!(Dictionary::digits_only().is_empty());
+    }
+
+    #[test]
+    fn test_parse() {
+        let dictionary: Dictionary = "# Dutch, in part\nnul 0\n\neen 1\n  twee   2\n"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            dictionary,
+            Dictionary::new([("nul", 0), ("een", 1), ("twee", 2)])
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "een 1\ntwee".parse::<Dictionary>(),
+            Err(MissingDigit { line: 2, column: 5 })
+        );
+        assert_eq!(
+            "een 10".parse::<Dictionary>(),
+            Err(InvalidDigit { line: 1, column: 5 })
+        );
+        assert_eq!(
+            "e3n 1".parse::<Dictionary>(),
+            Err(DigitInWord { line: 1, column: 2 })
+        );
+        assert_eq!(
+            "een 1 2".parse::<Dictionary>(),
+            Err(UnexpectedText { line: 1, column: 7 })
+        );
+    }
+}
@@ -0,0 +1,85 @@
+// Calibration documents: lines of lowercase letters with digits and spelled out
+// digit words mixed in, each line having at least one real digit for part one.
+
+use crate::Day01;
+use common::generator::Generator;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct Params {
+    pub lines: usize,
+    /// the most pieces (runs of letters, digits or digit words) on a line
+    pub max_pieces: usize,
+}
+
+impl Generator for Day01 {
+    type Params = Params;
+
+    fn params(size: usize) -> Self::Params {
+        Params {
+            lines: size,
+            max_pieces: 8,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, params: &Self::Params) -> String {
+        let mut document = String::new();
+
+        for _ in 0..params.lines {
+            let mut pieces: Vec<String> = (0..rng.gen_range(1..=params.max_pieces))
+                .map(|_| match rng.gen_range(0..3) {
+                    0 => digit(rng),
+                    1 => WORDS.choose(rng).unwrap().to_string(),
+                    _ => letters(rng),
+                })
+                .collect();
+
+            if !pieces
+                .iter()
+                .any(|piece| piece.as_bytes()[0].is_ascii_digit())
+            {
+                let i = rng.gen_range(0..=pieces.len());
+                pieces.insert(i, digit(rng));
+            }
+
+            document.push_str(&pieces.concat());
+            document.push('\n');
+        }
+
+        document
+    }
+}
+
+fn digit(rng: &mut StdRng) -> String {
+    rng.gen_range(1..=9).to_string()
+}
+
+fn letters(rng: &mut StdRng) -> String {
+    (0..rng.gen_range(1..=5))
+        .map(|_| rng.gen_range(b'a'..=b'z') as char)
+        .collect()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::Day01;
+    use common::generator::generate;
+    use common::solution::solve;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day01>(1, 200);
+
+        assert_eq!(input, generate::<Day01>(1, 200));
+        assert_eq!(input.lines().count(), 200);
+        assert!(input
+            .lines()
+            .all(|line| line.bytes().any(|b| b.is_ascii_digit())));
+        assert!(solve::<Day01>(&input, None).is_ok());
+    }
+}
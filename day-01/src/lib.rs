@@ -0,0 +1,56 @@
+pub mod calibration;
+pub mod dictionary;
+pub mod generator;
+pub mod policy;
+pub mod report;
+pub mod scanner;
+
+use calibration::sum_of_calibration_values;
+use common::error::ParseError;
+use common::solution::{Answer, Solution};
+use dictionary::Dictionary;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = String;
+
+    /// any text will do, as lines without digits add nothing, like the lines in part
+    /// two's example whose digits are all spelled out do in part one
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        sum_of_calibration_values(input, &Dictionary::digits_only()).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        sum_of_calibration_values(input, &Dictionary::english()).into()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::calibration::read_sum_of_calibration_values;
+    use crate::dictionary::Dictionary;
+    use crate::policy::Policy;
+    use crate::Day01;
+    use common::solution::{Answer, Solution};
+    use std::fs;
+
+    #[test]
+    fn test_parts_agree_with_streaming() {
+        let input = fs::read_to_string("test-input-part-2.txt").unwrap();
+        let document = Day01::parse(&input).unwrap();
+        let read = |dictionary| {
+            let sum =
+                read_sum_of_calibration_values(input.as_bytes(), &dictionary, Policy::FirstLast);
+            Answer::from(sum.unwrap())
+        };
+
+        assert_eq!(Day01::part_one(&document), read(Dictionary::digits_only()));
+        assert_eq!(Day01::part_one(&document), Answer::Unsigned(209));
+        assert_eq!(Day01::part_two(&document), read(Dictionary::english()));
+    }
+}
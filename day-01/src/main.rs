@@ -1,71 +1,169 @@
 // Advent of Code 2023: Day 1
 // https://adventofcode.com/2023/day/1
-// Usage: `cargo run <input-file> [--only-digits]
+// Usage: `cargo run [--format text|json] (<input-file>|-)... [--only-digits]
+//            [--language english|german|spanish|french] [--zero] [--dictionary <file>] [--report]
+//            [--policy first-last|concatenated|sum|first-<n>|last-<n>]
 
-use std::collections::HashMap;
+use common::error::ParseError;
+use common::input::{self, Source};
+use common::report::{self, Format, Record};
+use common::solution::{Part, Solved};
+use day_01::calibration::read_sum_of_calibration_values;
+use day_01::dictionary::{Dictionary, LANGUAGES};
+use day_01::policy::Policy;
+use day_01::report::line_reports;
+use day_01::scanner::Scanner;
 use std::env;
 use std::fs;
-
-const MAX_WORD_LENGTH: usize = 3;
+use std::io::{self, BufRead};
+use std::process;
+use std::time::Instant;
 
 fn main() {
-    let input_filename = env::args().nth(1).expect("please supply an input filename");
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let format = report::take_format(&mut args).unwrap_or_else(|error| fail(error));
+
+    let mut only_digits = false;
+    let mut report = false;
+    let mut policy = Policy::default();
+    let mut language = String::from("english");
+    let mut zero = false;
+    let mut dictionary = None;
+    let mut paths = vec![];
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--only-digits" => only_digits = true,
+            "--report" => report = true,
+            "--policy" => match args.next().map(|policy| policy.parse()) {
+                Some(Ok(value)) => policy = value,
+                Some(Err(error)) => fail(error),
+                None => fail("expected --policy <policy>"),
+            },
+            "--language" => match args.next() {
+                Some(name) if LANGUAGES.contains(&name.as_str()) => language = name,
+                _ => fail(format!("expected --language {}", LANGUAGES.join("|"))),
+            },
+            "--zero" => zero = true,
+            "--dictionary" => match args.next() {
+                Some(filename) => dictionary = Some(read_dictionary(&filename)),
+                None => fail("expected --dictionary <file>"),
+            },
+            _ => paths.push(arg),
+        }
+    }
 
-    let only_digits = match env::args().nth(2) {
-        Some(val) if val == "--only-digits" => true,
-        _ => false,
+    if zero && dictionary.is_some() {
+        fail("--zero only applies to the built in languages");
+    }
+
+    let dictionary = if only_digits {
+        Dictionary::digits_only()
+    } else {
+        dictionary.unwrap_or_else(|| {
+            Dictionary::language(&language, zero).expect("the language was checked")
+        })
     };
 
-    let input = fs::read_to_string(input_filename).expect("failed to read input");
-
-    let words = HashMap::from([
-        ("one", 1),
-        ("two", 2),
-        ("three", 3),
-        ("four", 4),
-        ("five", 5),
-        ("six", 6),
-        ("seven", 7),
-        ("eight", 8),
-        ("nine", 9),
-    ]);
-
-    let sum_of_calibration_values: usize = input
-        .trim()
-        .split("\n")
-        .map(|line| {
-            let line_length = line.len();
-            let line_bytes = line.as_bytes();
-            let mut digits = vec![];
-
-            for i in 0..line_length {
-                match line_bytes[i] {
-                    digit if digit >= b'0' && digit <= b'9' => digits.push((digit - b'0') as usize),
-                    _ => {
-                        if only_digits || i + MAX_WORD_LENGTH > line_length {
-                            continue;
-                        }
-
-                        for (&word, digit) in &words {
-                            if i + word.len() > line_length {
-                                continue;
-                            }
-
-                            if line[i..][..word.len()] == *word {
-                                digits.push(*digit)
-                            }
-                        }
-                    }
-                }
-            }
+    if report && format == Format::Json {
+        fail("--report only prints text");
+    }
 
-            digits.first().expect("there is a first digit") * 10
-                + digits.last().expect("there is a second digit")
+    // JSON records are answers to the puzzle's parts, which other settings aren't
+    let part = if policy != Policy::default() {
+        None
+    } else if dictionary == Dictionary::digits_only() {
+        Some(Part::One)
+    } else if dictionary == Dictionary::english() {
+        Some(Part::Two)
+    } else {
+        None
+    };
+    if format == Format::Json && part.is_none() {
+        fail("--format json only answers the puzzle's parts, so it takes no --policy, --language, --zero or --dictionary");
+    }
+
+    let sources = input::sources(paths).unwrap_or_else(|error| fail(error));
+
+    let succeeded = if report {
+        input::for_each(&sources, format, |_, input| {
+            print_report(input, &dictionary, policy);
+            Ok(())
         })
-        .sum();
+    } else {
+        input::for_each_reader(&sources, format, |source, reader| {
+            print_sum(source, reader, &dictionary, policy, format, part)
+        })
+    };
+
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+/// stream the input, printing only the sum of its calibration values
+fn print_sum(
+    source: &Source,
+    reader: &mut dyn BufRead,
+    dictionary: &Dictionary,
+    policy: Policy,
+    format: Format,
+    part: Option<Part>,
+) -> io::Result<()> {
+    let start = Instant::now();
+    let sum = read_sum_of_calibration_values(reader, dictionary, policy)?;
+
+    match format {
+        Format::Text => println!("The sum of the calibration values is: {}", sum),
+        Format::Json => {
+            let solved = Solved {
+                part: part.expect("JSON is only asked for with the puzzle's settings"),
+                answer: sum.into(),
+                elapsed: start.elapsed(),
+            };
+            println!(
+                "{}",
+                Record {
+                    day: 1,
+                    input: source,
+                    solved: &solved,
+                }
+            );
+        }
+    }
+    Ok(())
+}
+
+/// print how every line was read, then the sum over the lines that have a value
+fn print_report(input: &str, dictionary: &Dictionary, policy: Policy) {
+    let scanner = Scanner::new(dictionary);
+    let mut sum: u128 = 0;
+    let mut without_value = 0;
+
+    for line in line_reports(input, &scanner, policy) {
+        print!("{}", line);
+        match line.value() {
+            Ok(value) => sum += value as u128,
+            Err(_) => without_value += 1,
+        }
+    }
+
+    println!("The sum of the calibration values is: {}", sum);
+    if without_value > 0 {
+        println!("Lines without a value: {}", without_value);
+    }
+}
+
+fn read_dictionary(filename: &str) -> Dictionary {
+    let text = fs::read_to_string(filename)
+        .unwrap_or_else(|error| fail(format!("{}: {}", filename, error)));
+
+    text.parse()
+        .unwrap_or_else(|error| fail(format!("{}: {}", filename, ParseError::at_line(1, &error))))
+}
 
-    println!(
-        "The sum of the calibration values is: {}",
-        sum_of_calibration_values
-    );
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(2);
 }
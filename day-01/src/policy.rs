@@ -0,0 +1,181 @@
+// Rules for turning the digits found on a line into that line's calibration value.
+// The puzzle's rule is the first digit followed by the last one; the others answer
+// variants of the question about the same documents.
+
+use crate::scanner::Token;
+use common::error::Located;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Policy {
+    /// the first digit then the last, which may be the same one
+    #[default]
+    FirstLast,
+    /// every digit in order
+    Concatenated,
+    /// the digits added up
+    Sum,
+    /// the first n digits in order, or all of them on a shorter line
+    First(usize),
+    /// the last n digits in order, or all of them on a shorter line
+    Last(usize),
+}
+
+impl Policy {
+    /// the value of a line with the given tokens, in the order they start
+    pub fn value(&self, tokens: &[Token]) -> Result<usize, ValueError> {
+        if tokens.is_empty() {
+            return Err(ValueError::NoDigits);
+        }
+
+        let mut digits = self
+            .chosen(tokens.len())
+            .into_iter()
+            .map(|i| tokens[i].digit);
+
+        match self {
+            Policy::Sum => Ok(digits.sum()),
+            _ => digits.try_fold(0usize, |value, digit| {
+                value
+                    .checked_mul(10)
+                    .and_then(|value| value.checked_add(digit))
+                    .ok_or(ValueError::TooLarge)
+            }),
+        }
+    }
+
+    /// the positions among that many tokens of the ones the value is made from, in the
+    /// order they're used
+    pub fn chosen(&self, tokens: usize) -> Vec<usize> {
+        match self {
+            _ if tokens == 0 => vec![],
+            Policy::FirstLast => vec![0, tokens - 1],
+            Policy::Concatenated | Policy::Sum => (0..tokens).collect(),
+            Policy::First(n) => (0..tokens.min(*n)).collect(),
+            Policy::Last(n) => (tokens.saturating_sub(*n)..tokens).collect(),
+        }
+    }
+}
+
+impl fmt::Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Policy::FirstLast => write!(f, "first-last"),
+            Policy::Concatenated => write!(f, "concatenated"),
+            Policy::Sum => write!(f, "sum"),
+            Policy::First(n) => write!(f, "first-{}", n),
+            Policy::Last(n) => write!(f, "last-{}", n),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsePolicyError(String);
+
+impl fmt::Display for ParsePolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "expected --policy first-last, concatenated, sum, first-<n> or last-<n>, got {:?}",
+            self.0
+        )
+    }
+}
+
+impl FromStr for Policy {
+    type Err = ParsePolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let count = |n: &str| match n.parse() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(ParsePolicyError(s.to_string())),
+        };
+
+        match s {
+            "first-last" => Ok(Policy::FirstLast),
+            "concatenated" => Ok(Policy::Concatenated),
+            "sum" => Ok(Policy::Sum),
+            _ => match s.split_once('-') {
+                Some(("first", n)) => Ok(Policy::First(count(n)?)),
+                Some(("last", n)) => Ok(Policy::Last(count(n)?)),
+                _ => Err(ParsePolicyError(s.to_string())),
+            },
+        }
+    }
+}
+
+/// Why a line has no calibration value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueError {
+    NoDigits,
+    TooLarge,
+}
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValueError::NoDigits => write!(f, "expected a digit"),
+            ValueError::TooLarge => write!(f, "the value is too large"),
+        }
+    }
+}
+
+impl Located for ValueError {
+    fn column(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::dictionary::Dictionary;
+    use crate::policy::{Policy, ValueError};
+    use crate::scanner::Scanner;
+
+    #[test]
+    fn test_value() {
+        let scanner = Scanner::new(&Dictionary::english());
+        let tokens = scanner.tokens("xtwo1eightwo7");
+        let value = |policy: &str| policy.parse::<Policy>().unwrap().value(&tokens);
+
+        assert_eq!(value("first-last"), Ok(27));
+        assert_eq!(value("concatenated"), Ok(21827));
+        assert_eq!(value("sum"), Ok(20));
+        assert_eq!(value("first-3"), Ok(218));
+        assert_eq!(value("last-2"), Ok(27));
+        assert_eq!(value("last-9"), Ok(21827));
+
+        assert_eq!(Policy::FirstLast.chosen(5), vec![0, 4]);
+        assert_eq!(Policy::FirstLast.chosen(1), vec![0, 0]);
+        assert_eq!(Policy::Sum.chosen(3), vec![0, 1, 2]);
+        assert_eq!(Policy::Last(2).chosen(5), vec![3, 4]);
+        assert_eq!(Policy::First(2).chosen(0), Vec::<usize>::new());
+
+        let one = scanner.tokens("abc4");
+        assert_eq!(Policy::FirstLast.value(&one), Ok(44));
+        assert_eq!(Policy::First(2).value(&one), Ok(4));
+        assert_eq!(Policy::Sum.value(&[]), Err(ValueError::NoDigits));
+        assert_eq!(
+            Policy::Concatenated.value(&scanner.tokens(&"9".repeat(30))),
+            Err(ValueError::TooLarge)
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        for policy in [
+            Policy::FirstLast,
+            Policy::Concatenated,
+            Policy::Sum,
+            Policy::First(3),
+            Policy::Last(12),
+        ] {
+            assert_eq!(policy.to_string().parse(), Ok(policy));
+        }
+
+        assert!("first-0".parse::<Policy>().is_err());
+        assert!("middle-2".parse::<Policy>().is_err());
+        assert!("last".parse::<Policy>().is_err());
+    }
+}
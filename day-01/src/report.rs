@@ -0,0 +1,140 @@
+// An audit of how each line of a calibration document was read: every token found,
+// which of them the policy took, as the first and last digits or otherwise, and the
+// value it makes of them. Lines with no value are reported as such rather than stopping the run.
+
+use crate::policy::{Policy, ValueError};
+use crate::scanner::{Scanner, Token};
+use std::fmt;
+
+pub struct LineReport<'a> {
+    /// 1-based, counting every line of the input
+    pub number: usize,
+    pub line: &'a str,
+    pub tokens: Vec<Token>,
+    pub policy: Policy,
+}
+
+impl<'a> LineReport<'a> {
+    pub fn new(number: usize, line: &'a str, scanner: &Scanner, policy: Policy) -> Self {
+        Self {
+            number,
+            line,
+            tokens: scanner.tokens(line),
+            policy,
+        }
+    }
+
+    pub fn first(&self) -> Option<&Token> {
+        self.tokens.first()
+    }
+
+    pub fn last(&self) -> Option<&Token> {
+        self.tokens.last()
+    }
+
+    pub fn value(&self) -> Result<usize, ValueError> {
+        self.policy.value(&self.tokens)
+    }
+}
+
+/// a report for every line of the input but the blank ones, which have nothing to audit
+pub fn line_reports<'a>(
+    input: &'a str,
+    scanner: &'a Scanner,
+    policy: Policy,
+) -> impl Iterator<Item = LineReport<'a>> + 'a {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(move |(i, line)| LineReport::new(i + 1, line, scanner, policy))
+}
+
+/// The line and its value, then one row per token with its byte offset and text, marking
+/// the tokens the policy chose.
+impl fmt::Display for LineReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.value() {
+            Ok(value) => writeln!(f, "line {} {:?}: {}", self.number, self.line, value)?,
+            Err(ValueError::NoDigits) => {
+                return writeln!(f, "line {} {:?}: no digits", self.number, self.line)
+            }
+            Err(ValueError::TooLarge) => {
+                writeln!(f, "line {} {:?}: too large", self.number, self.line)?
+            }
+        }
+
+        let chosen = self.policy.chosen(self.tokens.len());
+        for (i, token) in self.tokens.iter().enumerate() {
+            let chosen = match self.policy {
+                Policy::FirstLast => match (i == chosen[0], i == chosen[1]) {
+                    (true, true) => "  first, last",
+                    (true, false) => "  first",
+                    (false, true) => "  last",
+                    (false, false) => "",
+                },
+                _ if chosen.contains(&i) => "  chosen",
+                _ => "",
+            };
+            let text = String::from_utf8_lossy(
+                &self.line.as_bytes()[token.offset..(token.offset + token.length)],
+            );
+
+            writeln!(
+                f,
+                "  {:>5}  {:<8} {}{}",
+                token.offset, text, token.digit, chosen
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::dictionary::Dictionary;
+    use crate::policy::{Policy, ValueError};
+    use crate::report::line_reports;
+    use crate::scanner::Scanner;
+
+    #[test]
+    fn test_line_reports() {
+        let scanner = Scanner::new(&Dictionary::english());
+        let reports: Vec<_> =
+            line_reports("two1nine\n\nabc\n  \n7\n\n", &scanner, Policy::FirstLast).collect();
+
+        assert_eq!(reports.len(), 3);
+        assert_eq!(reports[0].value(), Ok(29));
+        assert_eq!(reports[0].first().map(|token| token.offset), Some(0));
+        assert_eq!(reports[0].last().map(|token| token.offset), Some(4));
+        assert_eq!(reports[1].value(), Err(ValueError::NoDigits));
+        assert_eq!(reports[2].value(), Ok(77));
+
+        assert_eq!(
+            reports[0].to_string(),
+            "line 1 \"two1nine\": 29\n      0  two      2  first\n      3  1        1\n      4  nine     9  last\n"
+        );
+        assert_eq!(reports[1].to_string(), "line 3 \"abc\": no digits\n");
+        assert_eq!(
+            reports[2].to_string(),
+            "line 5 \"7\": 77\n      0  7        7  first, last\n"
+        );
+    }
+
+    #[test]
+    fn test_policy() {
+        let scanner = Scanner::new(&Dictionary::english());
+        let reports: Vec<_> = line_reports("two1nine\n", &scanner, Policy::Sum).collect();
+
+        assert_eq!(reports[0].value(), Ok(12));
+        assert!(reports[0]
+            .to_string()
+            .starts_with("line 1 \"two1nine\": 12\n"));
+
+        let reports: Vec<_> = line_reports("two1nine\n", &scanner, Policy::Last(2)).collect();
+        assert_eq!(
+            reports[0].to_string(),
+            "line 1 \"two1nine\": 19\n      0  two      2\n      3  1        1  chosen\n      4  nine     9  chosen\n"
+        );
+    }
+}
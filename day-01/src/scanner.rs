@@ -0,0 +1,150 @@
+// Finding every digit and digit word in a line in one pass over its bytes, with an
+// Aho-Corasick automaton built once from the digits and the dictionary's words.
+// Matches may overlap, so "eightwo" yields both an eight and a two.
+
+use crate::dictionary::Dictionary;
+use aho_corasick::{AhoCorasick, Match};
+
+/// A digit or digit word found in a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    /// the byte offset at which the token starts
+    pub offset: usize,
+    /// the length of the token in bytes
+    pub length: usize,
+    pub digit: usize,
+}
+
+pub struct Scanner {
+    automaton: AhoCorasick,
+    /// the digit each pattern of the automaton stands for, by pattern id
+    digits: Vec<usize>,
+}
+
+impl Scanner {
+    pub fn new(dictionary: &Dictionary) -> Self {
+        let mut patterns: Vec<String> = (0..10).map(|digit| digit.to_string()).collect();
+        let mut digits: Vec<usize> = (0..10).collect();
+
+        for (word, digit) in dictionary.words() {
+            patterns.push(word.clone());
+            digits.push(*digit);
+        }
+
+        let automaton = AhoCorasick::new(&patterns).expect("digit words make a small automaton");
+
+        Self { automaton, digits }
+    }
+
+    /// every token in the line, overlapping ones included, in the order they start
+    pub fn tokens(&self, line: &str) -> Vec<Token> {
+        let mut tokens: Vec<Token> = self
+            .automaton
+            .find_overlapping_iter(line)
+            .map(|found| self.token(found))
+            .collect();
+
+        // matches come out in the order they end, which differs when one word contains another
+        tokens.sort_by_key(|token| (token.offset, token.length));
+        tokens
+    }
+
+    fn token(&self, found: Match) -> Token {
+        Token {
+            offset: found.start(),
+            length: found.len(),
+            digit: self.digits[found.pattern().as_usize()],
+        }
+    }
+}
+
+/// the tokens found by trying every word at every byte, which the scanner replaced
+pub fn naive_tokens(line: &str, dictionary: &Dictionary) -> Vec<Token> {
+    let line_bytes = line.as_bytes();
+    let mut tokens = vec![];
+
+    for i in 0..line_bytes.len() {
+        if line_bytes[i].is_ascii_digit() {
+            tokens.push(Token {
+                offset: i,
+                length: 1,
+                digit: (line_bytes[i] - b'0') as usize,
+            });
+            continue;
+        }
+
+        // compare bytes, since i may not be on a character boundary
+        for (word, digit) in dictionary.words() {
+            if line_bytes[i..].starts_with(word.as_bytes()) {
+                tokens.push(Token {
+                    offset: i,
+                    length: word.len(),
+                    digit: *digit,
+                });
+            }
+        }
+    }
+
+    tokens.sort_by_key(|token| (token.offset, token.length));
+    tokens
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::dictionary::Dictionary;
+    use crate::scanner::{naive_tokens, Scanner, Token};
+    use crate::Day01;
+    use common::generator::generate;
+
+    #[test]
+    fn test_overlapping_tokens() {
+        let scanner = Scanner::new(&Dictionary::english());
+
+        assert_eq!(
+            scanner.tokens("eightwo3"),
+            vec![
+                Token {
+                    offset: 0,
+                    length: 5,
+                    digit: 8
+                },
+                Token {
+                    offset: 4,
+                    length: 3,
+                    digit: 2
+                },
+                Token {
+                    offset: 7,
+                    length: 1,
+                    digit: 3
+                },
+            ]
+        );
+        assert_eq!(scanner.tokens("abc"), vec![]);
+        assert_eq!(
+            Scanner::new(&Dictionary::digits_only())
+                .tokens("one2")
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_agrees_with_naive_tokens() {
+        for dictionary in [Dictionary::english(), Dictionary::german()] {
+            let scanner = Scanner::new(&dictionary);
+
+            for seed in 0..20 {
+                for line in generate::<Day01>(seed, 50).lines() {
+                    assert_eq!(scanner.tokens(line), naive_tokens(line, &dictionary));
+                }
+            }
+        }
+
+        let dictionary = "un 1\nuno 1\nnove 9\nove 8\n".parse().unwrap();
+        assert_eq!(
+            Scanner::new(&dictionary).tokens("unove"),
+            naive_tokens("unove", &dictionary)
+        );
+    }
+}
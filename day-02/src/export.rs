@@ -0,0 +1,69 @@
+// Games written out for other tools. JSON is one object per game and line:
+//
+//   {"id":1,"rounds":[{"blue":3,"red":4},{"blue":6,"green":2,"red":1}]}
+//
+// and CSV is a row per round with a column for every color in any of the games:
+//
+//   game,round,blue,green,red
+//   1,1,3,0,4
+
+use crate::game::{colors, Cubes, Game};
+use common::report::json_string;
+
+/// a JSON object of the counts by color
+pub fn cubes_json(cubes: &Cubes) -> String {
+    let counts: Vec<String> = cubes
+        .iter()
+        .map(|(color, count)| format!("{}:{}", json_string(color), count))
+        .collect();
+    format!("{{{}}}", counts.join(","))
+}
+
+pub fn game_json(game: &Game) -> String {
+    let rounds: Vec<String> = game.rounds().iter().map(cubes_json).collect();
+    format!(r#"{{"id":{},"rounds":[{}]}}"#, game.id(), rounds.join(","))
+}
+
+/// a header and then a row per round of every game, with rounds numbered from 1
+pub fn to_csv(games: &[Game]) -> String {
+    let colors = colors(games);
+    let mut csv = format!("game,round,{}\n", colors.join(","));
+
+    for game in games {
+        for (i, round) in game.rounds().iter().enumerate() {
+            let counts: Vec<String> = colors
+                .iter()
+                .map(|color| round.get(color).to_string())
+                .collect();
+            csv.push_str(&format!("{},{},{}\n", game.id(), i + 1, counts.join(",")));
+        }
+    }
+
+    csv
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::export::{game_json, to_csv};
+    use crate::game::Game;
+
+    #[test]
+    fn test_export() {
+        let games: Vec<Game> = [
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue",
+            "Game 2: 1 yellow",
+        ]
+        .iter()
+        .map(|line| line.parse().unwrap())
+        .collect();
+
+        assert_eq!(
+            game_json(&games[0]),
+            r#"{"id":1,"rounds":[{"blue":3,"red":4},{"blue":6,"green":2,"red":1}]}"#
+        );
+        assert_eq!(
+            to_csv(&games),
+            "game,round,blue,green,red,yellow\n1,1,3,0,4,0\n1,2,6,2,1,0\n2,1,0,0,0,1\n"
+        );
+    }
+}
@@ -0,0 +1,359 @@
+use common::error::{column_of, Located};
+use std::cmp::max;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::str::FromStr;
+use ParseGameError::*;
+
+/// Numbers of cubes by color, as shown in one round or held in a bag. Colors that
+/// aren't mentioned count as none, except that a bag may hold any number of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cubes(BTreeMap<String, usize>);
+
+impl Cubes {
+    pub fn get(&self, color: &str) -> usize {
+        self.0.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn add(&mut self, color: &str, count: usize) {
+        *self.0.entry(color.to_owned()).or_insert(0) += count;
+    }
+
+    /// the colors mentioned, in alphabetical order
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.0.iter().map(|(color, &count)| (color.as_str(), count))
+    }
+
+    pub fn total(&self) -> usize {
+        self.0.values().sum()
+    }
+
+    /// the most cubes of the color a bag like this holds, or None if it isn't limited
+    pub fn limit(&self, color: &str) -> Option<usize> {
+        self.0.get(color).copied()
+    }
+
+    /// the colors of which there are more cubes than the bag holds, with how many there are
+    pub fn exceeding<'a>(&'a self, bag: &'a Cubes) -> impl Iterator<Item = (&'a str, usize)> {
+        self.iter()
+            .filter(|&(color, count)| bag.limit(color).is_some_and(|limit| count > limit))
+    }
+
+    /// whether a bag holding `bag` cubes could have shown these
+    pub fn fits_in(&self, bag: &Cubes) -> bool {
+        self.exceeding(bag).next().is_none()
+    }
+}
+
+/// Written as in the puzzle, like `3 blue, 4 red`, in alphabetical order of color.
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let counts: Vec<String> = self
+            .iter()
+            .map(|(color, count)| format!("{} {}", count, color))
+            .collect();
+        write!(f, "{}", counts.join(", "))
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<(S, usize)> for Cubes {
+    fn from_iter<I: IntoIterator<Item = (S, usize)>>(iter: I) -> Self {
+        let mut cubes = Cubes::default();
+        for (color, count) in iter {
+            cubes.add(color.as_ref(), count);
+        }
+        cubes
+    }
+}
+
+/// The cubes shown in one reveal of a game.
+pub type Round = Cubes;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    id: usize,
+    rounds: Vec<Round>,
+}
+
+impl Game {
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// each reveal in the order it was made
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    /// the most cubes of each color shown at once, the smallest bag that could play the game
+    pub fn maxima(&self) -> Cubes {
+        let mut maxima = BTreeMap::new();
+
+        for (color, count) in self.rounds.iter().flat_map(Round::iter) {
+            let most = maxima.entry(color.to_owned()).or_insert(0);
+            *most = max(*most, count);
+        }
+
+        Cubes(maxima)
+    }
+
+    /// every cube shown over the whole game, counted once per round it was shown in
+    pub fn total_shown(&self) -> Cubes {
+        self.rounds.iter().flat_map(Round::iter).collect()
+    }
+
+    /// the index of the first round that couldn't have come from the bag
+    pub fn first_impossible_round(&self, bag: &Cubes) -> Option<usize> {
+        self.rounds.iter().position(|round| !round.fits_in(bag))
+    }
+
+    pub fn is_possible(&self, bag: &Cubes) -> Option<usize> {
+        match self.first_impossible_round(bag) {
+            None => Some(self.id),
+            Some(_) => None,
+        }
+    }
+
+    /// the product of the smallest bag's counts of each of the colors, which is zero if
+    /// the game never showed one of them
+    pub fn power<S: AsRef<str>>(&self, colors: &[S]) -> usize {
+        let maxima = self.maxima();
+        colors
+            .iter()
+            .map(|color| maxima.get(color.as_ref()))
+            .product()
+    }
+}
+
+/// Written as in the puzzle, which parses back to the same game.
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rounds: Vec<String> = self.rounds.iter().map(Round::to_string).collect();
+        write!(f, "Game {}: {}", self.id, rounds.join("; "))
+    }
+}
+
+/// every color shown in any of the games, in alphabetical order
+pub fn colors(games: &[Game]) -> Vec<&str> {
+    games
+        .iter()
+        .flat_map(|game| game.rounds.iter().flat_map(Round::colors))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseGameError {
+    MissingColon { column: usize },
+    InvalidId { column: usize },
+    InvalidCount { column: usize },
+    MissingColor { column: usize },
+    InvalidColor { column: usize, color: String },
+    UnexpectedText { column: usize },
+}
+
+impl fmt::Display for ParseGameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MissingColon { .. } => write!(f, "expected ':' after the game id"),
+            InvalidId { .. } => write!(f, "expected a game id"),
+            InvalidCount { .. } => write!(f, "expected a number of cubes"),
+            MissingColor { .. } => write!(f, "expected a cube color"),
+            InvalidColor { color, .. } => {
+                write!(f, "expected a color name but found '{}'", color)
+            }
+            UnexpectedText { .. } => write!(f, "expected ',' or ';' after the cube color"),
+        }
+    }
+}
+
+impl Located for ParseGameError {
+    fn column(&self) -> usize {
+        match self {
+            MissingColon { column }
+            | InvalidId { column }
+            | InvalidCount { column }
+            | MissingColor { column }
+            | InvalidColor { column, .. }
+            | UnexpectedText { column } => *column,
+        }
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseGameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (game, details) = s.split_once(':').ok_or(MissingColon {
+            column: s.len() + 1,
+        })?;
+
+        let id_text = game.split(' ').next_back().unwrap_or(game);
+        let id: usize = id_text.parse().map_err(|_| InvalidId {
+            column: column_of(s, id_text),
+        })?;
+
+        let rounds = details
+            .split(';')
+            .map(|round| parse_round(s, round))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Game { id, rounds })
+    }
+}
+
+/// parse the cubes of one round, with columns counted from the start of the game's line
+fn parse_round(s: &str, round_text: &str) -> Result<Round, ParseGameError> {
+    let mut round = Round::default();
+
+    for cube_info in round_text.split(',').map(str::trim) {
+        let mut words = cube_info.split(' ');
+
+        let number_text = words.next().unwrap_or(cube_info);
+        let number: usize = number_text.parse().map_err(|_| InvalidCount {
+            column: column_of(s, number_text),
+        })?;
+
+        let color = words.next().ok_or(MissingColor {
+            column: column_of(s, cube_info) + cube_info.len(),
+        })?;
+
+        if let Some(text) = words.next() {
+            return Err(UnexpectedText {
+                column: column_of(s, text),
+            });
+        }
+
+        if !color.chars().all(char::is_alphabetic) {
+            return Err(InvalidColor {
+                column: column_of(s, color),
+                color: color.to_owned(),
+            });
+        }
+
+        round.add(color, number);
+    }
+
+    Ok(round)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::game::{colors, Cubes, Game, ParseGameError::*};
+
+    fn cubes(counts: &[(&str, usize)]) -> Cubes {
+        counts.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let game: Game = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green"
+            .parse()
+            .unwrap();
+
+        assert_eq!(game.id(), 3);
+        assert_eq!(
+            game.rounds(),
+            [
+                cubes(&[("red", 20), ("green", 8), ("blue", 6)]),
+                cubes(&[("red", 4), ("green", 13), ("blue", 5)])
+            ]
+        );
+        assert_eq!(
+            game.maxima(),
+            cubes(&[("red", 20), ("green", 13), ("blue", 6)])
+        );
+    }
+
+    #[test]
+    fn test_history() {
+        let game: Game = "Game 1: 1 red; 3 blue, 13 green; 2 red, 2 green"
+            .parse()
+            .unwrap();
+        let bag = cubes(&[("red", 12), ("green", 12), ("blue", 12)]);
+
+        assert_eq!(game.first_impossible_round(&bag), Some(1));
+        assert_eq!(game.is_possible(&bag), None);
+        assert_eq!(
+            game.is_possible(&cubes(&[("red", 12), ("green", 13), ("blue", 14)])),
+            Some(1)
+        );
+        assert_eq!(game.total_shown().total(), 21);
+        assert_eq!(game.power(&["red", "green", "blue"]), 2 * 13 * 3);
+    }
+
+    #[test]
+    fn test_other_colors() {
+        let games: Vec<Game> = [
+            "Game 1: 3 yellow, 1 red; 2 purple",
+            "Game 2: 4 yellow, 2 red, 1 purple",
+        ]
+        .iter()
+        .map(|line| line.parse().unwrap())
+        .collect();
+        let colors = colors(&games);
+
+        assert_eq!(colors, ["purple", "red", "yellow"]);
+        assert_eq!(games[0].power(&colors), 2 * 3);
+        assert_eq!(games[1].power(&colors), 8);
+        assert_eq!(games[0].power(&["red", "blue"]), 0);
+
+        let bag = cubes(&[("yellow", 3), ("red", 2), ("purple", 2)]);
+        assert_eq!(games[0].is_possible(&bag), Some(1));
+        assert_eq!(games[1].is_possible(&bag), None);
+        assert_eq!(
+            games[1].rounds()[0].exceeding(&bag).collect::<Vec<_>>(),
+            [("yellow", 4)]
+        );
+
+        // a bag that doesn't mention purple may hold any number of purple cubes
+        let unlimited = cubes(&[("yellow", 4), ("red", 2)]);
+        assert_eq!(unlimited.limit("purple"), None);
+        assert_eq!(games[0].is_possible(&unlimited), Some(1));
+    }
+
+    #[test]
+    fn test_display() {
+        let text = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 1 red";
+        let game: Game = text.parse().unwrap();
+
+        assert_eq!(
+            game.to_string(),
+            "Game 3: 6 blue, 8 green, 20 red; 5 blue, 13 green, 4 red; 1 red"
+        );
+        assert_eq!(game.to_string().parse::<Game>().unwrap(), game);
+        assert_eq!(game.rounds()[2].to_string(), "1 red");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "Game 1 3 blue".parse::<Game>().unwrap_err(),
+            MissingColon { column: 14 }
+        );
+        assert_eq!(
+            "Game x: 3 blue".parse::<Game>().unwrap_err(),
+            InvalidId { column: 6 }
+        );
+        assert_eq!(
+            "Game 1: 3 blue, red".parse::<Game>().unwrap_err(),
+            InvalidCount { column: 17 }
+        );
+        assert_eq!(
+            "Game 1: 3 blue; 4".parse::<Game>().unwrap_err(),
+            MissingColor { column: 18 }
+        );
+        assert_eq!(
+            "Game 1: 3 blue, 4 y3llow".parse::<Game>().unwrap_err(),
+            InvalidColor {
+                column: 19,
+                color: String::from("y3llow")
+            }
+        );
+    }
+}
@@ -0,0 +1,66 @@
+// Game records: each game is a few rounds, and each round shows some of the colours
+// in any order.
+
+use crate::Day02;
+use common::generator::Generator;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+pub struct Params {
+    pub games: usize,
+    pub max_rounds: usize,
+    pub max_count: usize,
+}
+
+impl Generator for Day02 {
+    type Params = Params;
+
+    fn params(size: usize) -> Self::Params {
+        Params {
+            games: size,
+            max_rounds: 6,
+            max_count: 20,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, params: &Self::Params) -> String {
+        let mut record = String::new();
+
+        for id in 1..=params.games {
+            let rounds: Vec<String> = (0..rng.gen_range(1..=params.max_rounds))
+                .map(|_| {
+                    let shown = rng.gen_range(1..=COLORS.len());
+                    COLORS
+                        .choose_multiple(rng, shown)
+                        .map(|color| format!("{} {}", rng.gen_range(1..=params.max_count), color))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect();
+
+            record.push_str(&format!("Game {}: {}\n", id, rounds.join("; ")));
+        }
+
+        record
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::Day02;
+    use common::generator::generate;
+    use common::solution::solve;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day02>(2, 100);
+
+        assert_eq!(input, generate::<Day02>(2, 100));
+        assert_eq!(input.lines().count(), 100);
+        assert!(input.starts_with("Game 1: "));
+        assert!(solve::<Day02>(&input, None).is_ok());
+    }
+}
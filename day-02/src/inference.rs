@@ -0,0 +1,236 @@
+// Estimating what was in the bag from a game's reveals. Each round is taken to draw
+// its cubes from the full bag without replacement, and to put them back afterwards,
+// so the chance of a round is multivariate hypergeometric:
+//
+//   P(round | bag) = product over colors of C(in bag, shown) / C(bag total, round total)
+//
+// and the chance of the game is the product over its rounds. Candidate bags hold at
+// least the most of each color the game showed, and at most `max_extra` more, since
+// for some games the likelihood keeps creeping up as one color grows without bound.
+//
+// Only the denominator ties the colors together, and it depends on nothing but the bag's
+// total. Each color's numerator gains less from every cube added to it, so the best bag
+// of each total is the best bag one cube smaller with the cube that helps most added,
+// and the search is one walk up through the totals rather than every combination.
+
+use crate::export::cubes_json;
+use crate::game::{Cubes, Game};
+use std::fmt;
+
+/// The most likely bag for a game among those searched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub id: usize,
+    pub bag: Cubes,
+    pub log_likelihood: f64,
+    /// the smallest bag that could have played the game, for comparison
+    pub minimal: Cubes,
+    pub minimal_log_likelihood: f64,
+    /// whether some color is at the most searched, so a bigger search may find better
+    pub at_limit: bool,
+}
+
+impl Estimate {
+    /// one JSON object for the game
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"id":{},"bag":{},"log_likelihood":{},"minimal":{},"minimal_log_likelihood":{},"at_limit":{}}}"#,
+            self.id,
+            cubes_json(&self.bag),
+            self.log_likelihood,
+            cubes_json(&self.minimal),
+            self.minimal_log_likelihood,
+            self.at_limit
+        )
+    }
+}
+
+/// The game, its most likely bag and how likely that is, then the same for the smallest bag.
+impl fmt::Display for Estimate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Game {}: most likely {} (log-likelihood {:.3}{}), smallest {} ({:.3})",
+            self.id,
+            self.bag,
+            self.log_likelihood,
+            if self.at_limit {
+                ", at the search limit"
+            } else {
+                ""
+            },
+            self.minimal,
+            self.minimal_log_likelihood
+        )
+    }
+}
+
+/// the natural log of the chance of the game's reveals coming from the bag, or
+/// negative infinity if they couldn't have
+pub fn log_likelihood(game: &Game, bag: &Cubes) -> f64 {
+    let total = bag.total();
+
+    game.rounds()
+        .iter()
+        .map(|round| {
+            if !round.fits_in(bag) {
+                return f64::NEG_INFINITY;
+            }
+
+            let ways: f64 = round
+                .iter()
+                .map(|(color, shown)| ln_choose(bag.get(color), shown))
+                .sum();
+            ways - ln_choose(total, round.total())
+        })
+        .sum()
+}
+
+/// the bag that makes the game most likely, preferring fewer cubes between equally
+/// likely bags; searches bags with up to `max_extra` more of each color than the
+/// game ever showed at once
+pub fn most_likely_bag(game: &Game, max_extra: usize) -> Estimate {
+    let minimal = game.maxima();
+    let colors: Vec<&str> = minimal.colors().collect();
+
+    // the log of the ways to draw every reveal of the color from n cubes of it
+    let ways = |color: &str, n: usize| -> f64 {
+        game.rounds()
+            .iter()
+            .map(|round| ln_choose(n, round.get(color)))
+            .sum()
+    };
+    let gain = |color: &str, n: usize| ways(color, n + 1) - ways(color, n);
+
+    let mut bag = minimal.clone();
+    let mut gains: Vec<f64> = colors
+        .iter()
+        .map(|&color| gain(color, bag.get(color)))
+        .collect();
+    let mut best = minimal.clone();
+    let mut best_log_likelihood = log_likelihood(game, &minimal);
+
+    // add the cube that helps most, the first color of those that help as much, until
+    // every color is at the most searched
+    while let Some(i) = (0..colors.len())
+        .filter(|&i| bag.get(colors[i]) < minimal.get(colors[i]) + max_extra)
+        .max_by(|&a, &b| gains[a].total_cmp(&gains[b]).then(b.cmp(&a)))
+    {
+        bag.add(colors[i], 1);
+        gains[i] = gain(colors[i], bag.get(colors[i]));
+
+        // bags only grow, so an equally likely one is never preferred
+        let log_likelihood = log_likelihood(game, &bag);
+        if log_likelihood > best_log_likelihood + 1e-12 {
+            best = bag.clone();
+            best_log_likelihood = log_likelihood;
+        }
+    }
+
+    let at_limit = max_extra > 0
+        && colors
+            .iter()
+            .any(|&color| best.get(color) == minimal.get(color) + max_extra);
+
+    Estimate {
+        id: game.id(),
+        bag: best,
+        log_likelihood: best_log_likelihood,
+        minimal_log_likelihood: log_likelihood(game, &minimal),
+        minimal,
+        at_limit,
+    }
+}
+
+/// ln(n choose k), for k <= n
+fn ln_choose(n: usize, k: usize) -> f64 {
+    let k = k.min(n - k);
+    (0..k)
+        .map(|i| ((n - i) as f64).ln() - ((i + 1) as f64).ln())
+        .sum()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::game::{Cubes, Game};
+    use crate::inference::{ln_choose, log_likelihood, most_likely_bag};
+
+    fn bag(counts: &[(&str, usize)]) -> Cubes {
+        counts.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_log_likelihood() {
+        let game: Game = "Game 1: 1 red, 1 blue; 2 red".parse().unwrap();
+
+        assert!((ln_choose(5, 2) - 10f64.ln()).abs() < 1e-9);
+        assert!(
+            (log_likelihood(&game, &bag(&[("red", 2), ("blue", 1)])) - (2.0f64 / 9.0).ln()).abs()
+                < 1e-9
+        );
+        assert_eq!(
+            log_likelihood(&game, &bag(&[("red", 1), ("blue", 1)])),
+            f64::NEG_INFINITY
+        );
+    }
+
+    #[test]
+    fn test_most_likely_bag() {
+        let game: Game = "Game 1: 1 red, 1 blue; 2 red".parse().unwrap();
+        let estimate = most_likely_bag(&game, 5);
+
+        assert_eq!(estimate.bag, bag(&[("red", 3), ("blue", 1)]));
+        assert!((estimate.log_likelihood - 0.25f64.ln()).abs() < 1e-9);
+        assert_eq!(estimate.minimal, bag(&[("red", 2), ("blue", 1)]));
+        assert!(!estimate.at_limit);
+
+        // every bag with as many red as blue is equally likely, so take the smallest
+        let even: Game = "Game 2: 1 red; 1 blue".parse().unwrap();
+        assert_eq!(
+            most_likely_bag(&even, 5).bag,
+            bag(&[("red", 1), ("blue", 1)])
+        );
+
+        // this game only grows likelier as the bag grows, so the search runs into its limit
+        let reds: Game = "Game 3: 3 red; 1 blue".parse().unwrap();
+        assert!(most_likely_bag(&reds, 4).at_limit);
+        assert_eq!(most_likely_bag(&reds, 0).bag, reds.maxima());
+    }
+
+    #[test]
+    fn test_most_likely_bag_searches_every_bag() {
+        let games: Vec<Game> = [
+            "Game 1: 1 red, 1 blue; 2 red",
+            "Game 2: 3 red, 1 green; 1 blue, 2 green; 1 red, 1 blue, 1 green",
+            "Game 3: 2 blue; 1 red, 4 green; 3 blue, 1 green",
+        ]
+        .iter()
+        .map(|line| line.parse().unwrap())
+        .collect();
+
+        for game in &games {
+            let minimal = game.maxima();
+            let colors: Vec<(&str, usize)> = minimal.iter().collect();
+            let mut brute_force = f64::NEG_INFINITY;
+
+            // every combination of up to 4 more of each color
+            for n in 0..5usize.pow(colors.len() as u32) {
+                let bag: Cubes = (0..colors.len())
+                    .map(|i| {
+                        let (color, count) = colors[i];
+                        (color, count + n / 5usize.pow(i as u32) % 5)
+                    })
+                    .collect();
+                brute_force = brute_force.max(log_likelihood(game, &bag));
+            }
+
+            assert!((most_likely_bag(game, 4).log_likelihood - brute_force).abs() < 1e-9);
+        }
+
+        // many colors and a wide search, which would be 101^8 bags to try one by one
+        let rainbow: Game = "Game 4: 1 a, 2 b, 3 c, 1 d; 2 e, 1 f, 1 g, 4 h; 1 a, 1 h"
+            .parse()
+            .unwrap();
+        assert_eq!(most_likely_bag(&rainbow, 100).bag.colors().count(), 8);
+    }
+}
@@ -0,0 +1,57 @@
+pub mod export;
+pub mod game;
+pub mod generator;
+pub mod inference;
+pub mod query;
+
+use common::error::{parse_lines, ParseError};
+use common::solution::{Answer, Solution};
+use game::{colors, Cubes, Game};
+
+pub const RED: usize = 12;
+pub const GREEN: usize = 13;
+pub const BLUE: usize = 14;
+
+/// the bag part one asks about, which holds only red, green and blue cubes, so none of
+/// the other colors the games show
+pub fn puzzle_bag(colors: &[&str]) -> Cubes {
+    let mut bag: Cubes = [("red", RED), ("green", GREEN), ("blue", BLUE)]
+        .into_iter()
+        .collect();
+    for color in colors {
+        if bag.limit(color).is_none() {
+            bag.add(color, 0);
+        }
+    }
+    bag
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_one(games: &Self::Input) -> Answer {
+        let bag = puzzle_bag(&colors(games));
+
+        games
+            .iter()
+            .filter_map(|game| game.is_possible(&bag))
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_two(games: &Self::Input) -> Answer {
+        let colors = colors(games);
+
+        games
+            .iter()
+            .map(|game| game.power(&colors))
+            .sum::<usize>()
+            .into()
+    }
+}
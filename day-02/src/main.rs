@@ -1,123 +1,177 @@
 // Advent of Code 2023: Day 2
 // https://adventofcode.com/2023/day/2
-// Usage: `cargo run <input-file>
-
-use std::cmp::max;
+// Usage: `cargo run [--format text|json] (<input-file>|-)...
+//            [--bag <color><=<n>,...]... [--bags <file>] [--infer [--extra <n>]]
+//            [--export text|json|csv]
+
+use common::error::Located;
+use common::input::{self, Headers};
+use common::report::{self, Format};
+use common::solution::Solution;
+use day_02::export::{game_json, to_csv};
+use day_02::game::Game;
+use day_02::inference::most_likely_bag;
+use day_02::query::{parse_bags, Bag, Query};
+use day_02::{Day02, BLUE, GREEN, RED};
 use std::env;
 use std::fs;
-use std::str::FromStr;
-
-#[derive(Debug)]
-struct Game {
-    id: usize,
-    max_red: usize,
-    max_green: usize,
-    max_blue: usize,
-}
+use std::process;
+
+/// how many more of each color than a game showed to consider when inferring its bag
+const DEFAULT_EXTRA: usize = 10;
 
-impl Game {
-    fn is_possible(&self, red: usize, green: usize, blue: usize) -> Option<usize> {
-        if self.max_red <= red && self.max_green <= green && self.max_blue <= blue {
-            return Some(self.id);
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let format = report::take_format(&mut args).unwrap_or_else(|error| fail(error));
+
+    let mut bags: Vec<Bag> = vec![];
+    let mut export = None;
+    let mut infer = false;
+    let mut extra = DEFAULT_EXTRA;
+    let mut paths = vec![];
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bag" => match args.next().map(|bag| bag.parse()) {
+                Some(Ok(bag)) => bags.push(bag),
+                Some(Err(error)) => fail(format!("--bag: column {}: {}", error.column(), error)),
+                None => fail("expected --bag <color><=<n>,..."),
+            },
+            "--bags" => match args.next() {
+                Some(filename) => bags.extend(read_bags(&filename)),
+                None => fail("expected --bags <file>"),
+            },
+            "--export" => match args.next().as_deref() {
+                Some(to @ ("text" | "json" | "csv")) => export = Some(to.to_owned()),
+                _ => fail("expected --export text, json or csv"),
+            },
+            "--infer" => infer = true,
+            "--extra" => match args.next().map(|n| n.parse()) {
+                Some(Ok(n)) => extra = n,
+                _ => fail("expected --extra <n>"),
+            },
+            _ => paths.push(arg),
         }
-        None
     }
 
-    fn power(&self) -> usize {
-        self.max_red * self.max_green * self.max_blue
+    let sources = input::sources(paths).unwrap_or_else(|error| fail(error));
+
+    if let Some(to) = &export {
+        // one export of the games from every input, so it still parses back
+        let mut games = vec![];
+        let succeeded = input::for_each(&sources, Headers::Hidden, |_, input| {
+            games.extend(Day02::parse(input)?);
+            Ok(())
+        });
+        print_export(&games, &bags, to);
+
+        if !succeeded {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let succeeded = input::for_each(&sources, format, |source, input| {
+        if infer {
+            let games = Day02::parse(input)?;
+            print_estimates(&games, extra, format);
+            return Ok(());
+        }
+
+        if !bags.is_empty() {
+            let games = Day02::parse(input)?;
+            print_queries(&games, &bags, format);
+            return Ok(());
+        }
+
+        if format == Format::Json {
+            return report::print_records::<Day02>(2, source, input, None);
+        }
+
+        let games = Day02::parse(input)?;
+
+        println!(
+            "The sum of the possible games for rgb({}, {}, {}) is: {}",
+            RED,
+            GREEN,
+            BLUE,
+            Day02::part_one(&games)
+        );
+
+        println!(
+            "The sum of the powers of each set is: {}",
+            Day02::part_two(&games)
+        );
+        Ok(())
+    });
+
+    if !succeeded {
+        process::exit(1);
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct ParseGameError;
-
-impl FromStr for Game {
-    type Err = ParseGameError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut major_parts = s.split(':');
-        let id: usize = major_parts
-            .next()
-            .expect("game section exists")
-            .split(' ')
-            .last()
-            .expect("game id exists")
-            .parse()
-            .expect("game id is a positive integer");
-
-        let mut max_red = 0;
-        let mut max_green = 0;
-        let mut max_blue = 0;
-
-        for mut cube_info in major_parts
-            .next()
-            .expect("game details are in expected format")
-            .trim()
-            .split([',', ';'])
-            .map(|game_part| game_part.trim().split(' '))
-        {
-            let number: usize = cube_info
-                .next()
-                .expect("has a number")
-                .parse()
-                .expect("number is a positive integer");
-
-            let color = cube_info.next().expect("has a color");
-
-            match color {
-                "red" => {
-                    max_red = max(max_red, number);
-                }
-                "green" => {
-                    max_green = max(max_green, number);
-                }
-                "blue" => {
-                    max_blue = max(max_blue, number);
-                }
-                _ => {
-                    unreachable!()
+/// which games each bag could have played, and why the others couldn't
+fn print_queries(games: &[Game], bags: &[Bag], format: Format) {
+    for (i, bag) in bags.iter().enumerate() {
+        let query = Query::new(games, bag);
+
+        match format {
+            Format::Text => {
+                if i > 0 {
+                    println!();
                 }
+                print!("{}", query);
             }
+            Format::Json => println!("{}", query.to_json()),
         }
-
-        Ok(Game {
-            id,
-            max_red,
-            max_green,
-            max_blue,
-        })
     }
 }
 
-fn main() {
-    let input_filename = env::args().nth(1).expect("please supply an input filename");
-
-    let red = 12;
-    let green = 13;
-    let blue = 14;
+/// the games, or only those every bag could have played, as puzzle text, JSON or CSV
+fn print_export(games: &[Game], bags: &[Bag], to: &str) {
+    let games: Vec<Game> = games
+        .iter()
+        .filter(|game| {
+            bags.iter()
+                .all(|bag| game.first_impossible_round(&bag.0).is_none())
+        })
+        .cloned()
+        .collect();
 
-    let input = fs::read_to_string(input_filename).expect("failed to read input");
+    match to {
+        "csv" => print!("{}", to_csv(&games)),
+        _ => {
+            for game in &games {
+                match to {
+                    "json" => println!("{}", game_json(game)),
+                    _ => println!("{}", game),
+                }
+            }
+        }
+    }
+}
 
-    let games: Vec<Game> = input
-        .trim()
-        .split("\n")
-        .map(|line| line.parse().expect("valid game string format"))
-        .collect();
+/// the most likely bag for each game
+fn print_estimates(games: &[Game], extra: usize, format: Format) {
+    for game in games {
+        let estimate = most_likely_bag(game, extra);
 
-    let sum_of_possible_games: usize = games
-        .iter()
-        .filter_map(|game| game.is_possible(red, green, blue))
-        .sum();
+        match format {
+            Format::Text => println!("{}", estimate),
+            Format::Json => println!("{}", estimate.to_json()),
+        }
+    }
+}
 
-    let sum_of_power_of_sets: usize = games.iter().map(Game::power).sum();
+fn read_bags(filename: &str) -> Vec<Bag> {
+    let text = fs::read_to_string(filename)
+        .unwrap_or_else(|error| fail(format!("{}: {}", filename, error)));
 
-    println!(
-        "The sum of the possible games for rgb({}, {}, {}) is: {}",
-        red, green, blue, sum_of_possible_games
-    );
+    parse_bags(&text).unwrap_or_else(|error| fail(format!("{}: {}", filename, error)))
+}
 
-    println!(
-        "The sum of the powers of each set is: {}",
-        sum_of_power_of_sets
-    );
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(2);
 }
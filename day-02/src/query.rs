@@ -0,0 +1,298 @@
+// Asking which games a hypothetical bag could have played. A bag is written as limits
+// on each color, like `red<=12,green<=13,blue<=14`, and may hold any number of a color
+// it doesn't mention, so `red<=0` rules out only the games that show red. A file of bags
+// has one per line, skipping blank lines and lines starting with '#'.
+
+use crate::game::{Cubes, Game};
+use common::error::{column_of, Located, ParseError};
+use common::report::json_string;
+use std::fmt;
+use std::str::FromStr;
+use ParseBagError::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag(pub Cubes);
+
+/// Written back as constraints, in alphabetical order of color.
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let constraints: Vec<String> = self
+            .0
+            .iter()
+            .map(|(color, limit)| format!("{}<={}", color, limit))
+            .collect();
+        write!(f, "{}", constraints.join(","))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseBagError {
+    MissingOperator { column: usize },
+    InvalidColor { column: usize },
+    InvalidLimit { column: usize },
+    DuplicateColor { column: usize },
+}
+
+impl fmt::Display for ParseBagError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MissingOperator { .. } => write!(f, "expected a constraint like red<=12"),
+            InvalidColor { .. } => write!(f, "expected a color name"),
+            InvalidLimit { .. } => write!(f, "expected a number of cubes"),
+            DuplicateColor { .. } => write!(f, "expected each color only once"),
+        }
+    }
+}
+
+impl Located for ParseBagError {
+    fn column(&self) -> usize {
+        match self {
+            MissingOperator { column }
+            | InvalidColor { column }
+            | InvalidLimit { column }
+            | DuplicateColor { column } => *column,
+        }
+    }
+}
+
+impl FromStr for Bag {
+    type Err = ParseBagError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut limits = Cubes::default();
+
+        for constraint in s.split(',').map(str::trim) {
+            let (color, limit) = constraint.split_once("<=").ok_or(MissingOperator {
+                column: column_of(s, constraint),
+            })?;
+            let (color, limit) = (color.trim(), limit.trim());
+
+            if color.is_empty() || !color.chars().all(char::is_alphabetic) {
+                return Err(InvalidColor {
+                    column: column_of(s, constraint),
+                });
+            }
+
+            if limits.colors().any(|seen| seen == color) {
+                return Err(DuplicateColor {
+                    column: column_of(s, color),
+                });
+            }
+
+            let limit = limit.parse().map_err(|_| InvalidLimit {
+                column: column_of(s, limit),
+            })?;
+
+            limits.add(color, limit);
+        }
+
+        Ok(Bag(limits))
+    }
+}
+
+/// the bags in a file of them
+pub fn parse_bags(s: &str) -> Result<Vec<Bag>, ParseError> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| {
+            line.parse()
+                .map_err(|error| ParseError::at_line(i + 1, &error))
+        })
+        .collect()
+}
+
+/// A game the bag couldn't have played, and why.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Failure {
+    pub id: usize,
+    /// 1-based, the first round showing more of some color than the bag holds
+    pub round: usize,
+    /// every color the game showed more of than the bag holds, with the most it showed
+    pub exceeded: Vec<(String, usize)>,
+}
+
+/// The answer to asking which games a bag could have played.
+#[derive(Debug)]
+pub struct Query<'a> {
+    pub bag: &'a Bag,
+    pub possible: Vec<usize>,
+    pub failures: Vec<Failure>,
+}
+
+impl<'a> Query<'a> {
+    pub fn new(games: &[Game], bag: &'a Bag) -> Self {
+        let mut possible = vec![];
+        let mut failures = vec![];
+
+        for game in games {
+            match game.first_impossible_round(&bag.0) {
+                None => possible.push(game.id()),
+                Some(round) => failures.push(Failure {
+                    id: game.id(),
+                    round: round + 1,
+                    exceeded: game
+                        .maxima()
+                        .exceeding(&bag.0)
+                        .map(|(color, count)| (color.to_owned(), count))
+                        .collect(),
+                }),
+            }
+        }
+
+        Self {
+            bag,
+            possible,
+            failures,
+        }
+    }
+
+    pub fn sum(&self) -> usize {
+        self.possible.iter().sum()
+    }
+
+    /// one JSON object for the whole query
+    pub fn to_json(&self) -> String {
+        let ids = |ids: &[usize]| {
+            ids.iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let failures: Vec<String> = self
+            .failures
+            .iter()
+            .map(|failure| {
+                let exceeded: Vec<String> = failure
+                    .exceeded
+                    .iter()
+                    .map(|(color, count)| format!("{}:{}", json_string(color), count))
+                    .collect();
+                format!(
+                    r#"{{"id":{},"round":{},"exceeded":{{{}}}}}"#,
+                    failure.id,
+                    failure.round,
+                    exceeded.join(",")
+                )
+            })
+            .collect();
+
+        format!(
+            r#"{{"bag":{},"possible":[{}],"sum":{},"impossible":[{}]}}"#,
+            json_string(&self.bag.to_string()),
+            ids(&self.possible),
+            self.sum(),
+            failures.join(",")
+        )
+    }
+}
+
+/// The bag, the games it could have played and their sum, then a line per other game.
+impl fmt::Display for Query<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let possible: Vec<String> = self.possible.iter().map(usize::to_string).collect();
+
+        writeln!(f, "Bag {}", self.bag)?;
+        writeln!(f, "  possible games: {}", possible.join(", "))?;
+        writeln!(f, "  sum of their ids: {}", self.sum())?;
+
+        for failure in &self.failures {
+            let exceeded: Vec<String> = failure
+                .exceeded
+                .iter()
+                .map(|(color, count)| format!("{} {} > {}", count, color, self.bag.0.get(color)))
+                .collect();
+            writeln!(
+                f,
+                "  game {} impossible from round {}: {}",
+                failure.id,
+                failure.round,
+                exceeded.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::game::Game;
+    use crate::query::{parse_bags, Bag, Failure, ParseBagError::*, Query};
+    use common::error::ParseError;
+
+    fn games() -> Vec<Game> {
+        [
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+            "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+        ]
+        .iter()
+        .map(|line| line.parse().unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let bag: Bag = "red<=12, green <= 13,blue<=14".parse().unwrap();
+
+        assert_eq!(bag.0.get("green"), 13);
+        assert_eq!(bag.to_string(), "blue<=14,green<=13,red<=12");
+
+        assert_eq!(
+            "red<=12,green13".parse::<Bag>(),
+            Err(MissingOperator { column: 9 })
+        );
+        assert_eq!("<=3".parse::<Bag>(), Err(InvalidColor { column: 1 }));
+        assert_eq!("red<=x".parse::<Bag>(), Err(InvalidLimit { column: 6 }));
+        assert_eq!(
+            "red<=1,red<=2".parse::<Bag>(),
+            Err(DuplicateColor { column: 8 })
+        );
+
+        let bags =
+            parse_bags("# the puzzle's bag\nred<=12,green<=13,blue<=14\n\nred<=20\n").unwrap();
+        assert_eq!(bags.len(), 2);
+        assert_eq!(
+            parse_bags("red<=1\nred<=y\n").unwrap_err(),
+            ParseError::new(2, 6, "expected a number of cubes")
+        );
+    }
+
+    #[test]
+    fn test_query() {
+        let games = games();
+        let bag: Bag = "red<=12,green<=13,blue<=14".parse().unwrap();
+        let query = Query::new(&games, &bag);
+
+        assert_eq!(query.possible, [1, 5]);
+        assert_eq!(query.sum(), 6);
+        assert_eq!(
+            query.failures,
+            [Failure {
+                id: 3,
+                round: 1,
+                exceeded: vec![(String::from("red"), 20)]
+            }]
+        );
+        assert_eq!(
+            query.to_string(),
+            "Bag blue<=14,green<=13,red<=12\n  possible games: 1, 5\n  sum of their ids: 6\n  game 3 impossible from round 1: 20 red > 12\n"
+        );
+        assert_eq!(
+            query.to_json(),
+            r#"{"bag":"blue<=14,green<=13,red<=12","possible":[1,5],"sum":6,"impossible":[{"id":3,"round":1,"exceeded":{"red":20}}]}"#
+        );
+
+        // colors the bag doesn't mention aren't limited
+        let reds: Bag = "red<=20".parse().unwrap();
+        assert_eq!(Query::new(&games, &reds).possible, [1, 3, 5]);
+        let possible: Vec<usize> = games
+            .iter()
+            .filter_map(|game| game.is_possible(&reds.0))
+            .collect();
+        assert_eq!(possible, [1, 3, 5]);
+
+        let no_red: Bag = "red<=0".parse().unwrap();
+        assert_eq!(Query::new(&games, &no_red).possible, Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,78 @@
+// Engine schematics: numbers of up to three digits and symbols scattered over a
+// grid of dots, with stars more common than the other symbols so there are gears.
+
+use crate::Day03;
+use common::generator::Generator;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const SYMBOLS: &[u8] = b"***#+$/=%@&-";
+
+pub struct Params {
+    pub rows: usize,
+    pub columns: usize,
+    /// the chance of a number starting at each free cell
+    pub number_density: f64,
+    /// the chance of a symbol at each free cell
+    pub symbol_density: f64,
+}
+
+impl Generator for Day03 {
+    type Params = Params;
+
+    fn params(size: usize) -> Self::Params {
+        Params {
+            rows: size.max(2),
+            columns: size.max(1),
+            number_density: 0.15,
+            symbol_density: 0.08,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, params: &Self::Params) -> String {
+        let mut schematic = String::new();
+
+        for _ in 0..params.rows {
+            let mut row: Vec<u8> = Vec::with_capacity(params.columns);
+
+            while row.len() < params.columns {
+                let after_number = row.last().is_some_and(u8::is_ascii_digit);
+                let roll: f64 = rng.gen();
+
+                if roll < params.number_density && !after_number {
+                    let length = rng.gen_range(1..=3).min(params.columns - row.len());
+                    let number =
+                        rng.gen_range(10usize.pow(length as u32 - 1)..10usize.pow(length as u32));
+                    row.extend(number.to_string().bytes());
+                } else if roll < params.number_density + params.symbol_density {
+                    row.push(*SYMBOLS.choose(rng).unwrap());
+                } else {
+                    row.push(b'.');
+                }
+            }
+
+            schematic.push_str(&String::from_utf8(row).unwrap());
+            schematic.push('\n');
+        }
+
+        schematic
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::Day03;
+    use common::generator::generate;
+    use common::solution::solve;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day03>(3, 140);
+
+        assert_eq!(input, generate::<Day03>(3, 140));
+        assert_eq!(input.lines().count(), 140);
+        assert!(input.lines().all(|line| line.len() == 140));
+        assert!(solve::<Day03>(&input, None).is_ok());
+    }
+}
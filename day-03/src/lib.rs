@@ -1 +1,50 @@
+pub mod generator;
+pub mod render;
 pub mod schematic;
+
+use common::error::ParseError;
+use common::solution::{Answer, Solution};
+use schematic::Schematic;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Schematic::new(input).map_err(|error| ParseError::at_line(1, &error))
+    }
+
+    /// numbers as long as parsing allows can add up to more than an answer holds
+    fn part_one(schematic: &Self::Input) -> Answer {
+        schematic
+            .part_numbers()
+            .into_iter()
+            .try_fold(0usize, usize::checked_add)
+            .map_or(Answer::Unanswerable, Answer::from)
+    }
+
+    fn part_two(schematic: &Self::Input) -> Answer {
+        schematic
+            .gear_ratios()
+            .into_iter()
+            .try_fold(0usize, |sum, ratio| sum.checked_add(ratio?))
+            .map_or(Answer::Unanswerable, Answer::from)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::Day03;
+    use common::solution::{Answer, Solution};
+
+    #[test]
+    fn test_answers_too_large() {
+        let max = usize::MAX.to_string();
+        let input = format!("{}.{}\n{}\n", max, max, "*".repeat(2 * max.len() + 1));
+        let schematic = Day03::parse(&input).unwrap();
+
+        assert_eq!(Day03::part_one(&schematic), Answer::Unanswerable);
+        assert_eq!(Day03::part_two(&schematic), Answer::Unanswerable);
+    }
+}
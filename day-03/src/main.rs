@@ -1,19 +1,193 @@
 // Advent of Code 2023: Day 3
 // https://adventofcode.com/2023/day/3
-// Usage: `cargo run <input-file>
+// Usage: `cargo run [--format text|json] (<input-file>|-)... [--gears <symbol> [--arity <n>]]
+//            [--render ansi|html] [--symbols]
 
+use common::input;
+use common::report::{self, json_string, Format};
+use common::solution::Solution;
+use day_03::render::{render_ansi, render_html};
 use day_03::schematic::Schematic;
+use day_03::Day03;
 use std::env;
-use std::fs;
+use std::process;
 
 fn main() {
-    let input_filename = env::args().nth(1).expect("please supply an input filename");
-    let input = fs::read_to_string(input_filename).expect("failed to read input");
-    let schematic = Schematic::new(&input);
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let format = report::take_format(&mut args).unwrap_or_else(|error| fail(error));
 
-    let sum_of_part_numbers: usize = schematic.part_numbers().iter().sum();
-    let sum_of_gear_ratios: usize = schematic.gear_ratios().iter().sum();
+    let mut gears = None;
+    let mut arity = None;
+    let mut render = None;
+    let mut symbols = false;
+    let mut paths = vec![];
 
-    println!("The sum of the part numbers is {}", sum_of_part_numbers);
-    println!("The sum of the gear ratios is {}", sum_of_gear_ratios);
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--gears" => match args.next().as_deref().map(str::as_bytes) {
+                Some(&[symbol]) => gears = Some(symbol),
+                _ => fail("expected --gears <symbol>, a single character"),
+            },
+            "--arity" => match args.next().map(|n| n.parse()) {
+                Some(Ok(n)) => arity = Some(n),
+                _ => fail("expected --arity <n>"),
+            },
+            "--render" => match args.next().as_deref() {
+                Some(style @ ("ansi" | "html")) => render = Some(style.to_owned()),
+                _ => fail("expected --render ansi or html"),
+            },
+            "--symbols" => symbols = true,
+            _ => paths.push(arg),
+        }
+    }
+
+    if [gears.is_some(), render.is_some(), symbols]
+        .into_iter()
+        .filter(|&mode| mode)
+        .count()
+        > 1
+    {
+        fail("--gears, --render and --symbols each choose what to print, so give only one");
+    }
+    if arity.is_some() && gears.is_none() {
+        fail("--arity only applies to --gears");
+    }
+    if render.is_some() && format == Format::Json {
+        fail("--render only prints ANSI or HTML");
+    }
+    let arity = arity.unwrap_or(2);
+
+    let sources = input::sources(paths).unwrap_or_else(|error| fail(error));
+
+    let succeeded = input::for_each(&sources, format, |source, input| {
+        if let Some(style) = &render {
+            let schematic = Day03::parse(input)?;
+            match style.as_str() {
+                "html" => print!("{}", render_html(&schematic)),
+                _ => print!("{}", render_ansi(&schematic)),
+            }
+            return Ok(());
+        }
+
+        if symbols {
+            let schematic = Day03::parse(input)?;
+            print_symbols(&schematic, format);
+            return Ok(());
+        }
+
+        if let Some(symbol) = gears {
+            let schematic = Day03::parse(input)?;
+            print_gears(&schematic, symbol, arity, format);
+            return Ok(());
+        }
+
+        if format == Format::Json {
+            return report::print_records::<Day03>(3, source, input, None);
+        }
+
+        let schematic = Day03::parse(input)?;
+
+        println!(
+            "The sum of the part numbers is {}",
+            Day03::part_one(&schematic)
+        );
+        println!(
+            "The sum of the gear ratios is {}",
+            Day03::part_two(&schematic)
+        );
+        Ok(())
+    });
+
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+/// every symbol touching exactly `arity` numbers, with its ratio, then their sum
+fn print_gears(schematic: &Schematic, symbol: u8, arity: usize, format: Format) {
+    let gears = schematic.gears(symbol, arity);
+
+    for gear in &gears {
+        let (row, column) = schematic.grid.coordinates(gear.index);
+        let values: Vec<String> = gear
+            .numbers
+            .iter()
+            .map(|number| number.value.to_string())
+            .collect();
+
+        match format {
+            Format::Text => println!(
+                "{} at row {}, column {}: {} = {}",
+                symbol as char,
+                row + 1,
+                column + 1,
+                values.join(" * "),
+                gear.ratio
+                    .map_or(String::from("too large"), |ratio| ratio.to_string())
+            ),
+            Format::Json => println!(
+                r#"{{"symbol":{},"row":{},"column":{},"numbers":[{}],"ratio":{}}}"#,
+                json_string(&(symbol as char).to_string()),
+                row + 1,
+                column + 1,
+                values.join(","),
+                gear.ratio
+                    .map_or(String::from("null"), |ratio| ratio.to_string())
+            ),
+        }
+    }
+
+    if format == Format::Text {
+        match gears
+            .iter()
+            .try_fold(0usize, |sum, gear| sum.checked_add(gear.ratio?))
+        {
+            Some(sum) => println!("The sum of the ratios is {}", sum),
+            None => println!("The sum of the ratios is too large"),
+        }
+    }
+}
+
+/// a row for each kind of symbol, then the symbols touching no numbers
+fn print_symbols(schematic: &Schematic, format: Format) {
+    let stats = schematic.symbol_stats();
+
+    if format == Format::Json {
+        for stats in &stats {
+            println!(
+                r#"{{"symbol":{},"cells":{},"lonely":{},"numbers":{},"only":{},"sum":{}}}"#,
+                json_string(&(stats.symbol as char).to_string()),
+                stats.cells,
+                stats.lonely,
+                stats.numbers,
+                stats.only,
+                stats.sum
+            );
+        }
+        return;
+    }
+
+    println!("symbol  cells  lonely  numbers  only        sum");
+    for stats in &stats {
+        println!(
+            "{:>6}  {:>5}  {:>6}  {:>7}  {:>4}  {:>9}",
+            stats.symbol as char, stats.cells, stats.lonely, stats.numbers, stats.only, stats.sum
+        );
+    }
+
+    for i in schematic.lonely_symbols() {
+        let (row, column) = schematic.grid.coordinates(i);
+        println!(
+            "{} at row {}, column {} touches no numbers",
+            schematic.grid[i] as char,
+            row + 1,
+            column + 1
+        );
+    }
+}
+
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(2);
 }
@@ -0,0 +1,223 @@
+// The schematic drawn for reviewing by eye, in color for a terminal or as a standalone
+// HTML page. Part numbers are green, numbers that touch no symbol are dimmed, and
+// gears are highlighted, with each row followed by the ratios of the gears in it.
+
+use crate::schematic::{Gear, Schematic};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Part,
+    NotPart,
+    Gear,
+    Symbol,
+    Empty,
+}
+
+impl Kind {
+    fn ansi(self) -> &'static str {
+        match self {
+            Kind::Part => "\x1b[32m",
+            Kind::NotPart => "\x1b[2m",
+            Kind::Gear => "\x1b[1;33m",
+            Kind::Symbol => "\x1b[1m",
+            Kind::Empty => "",
+        }
+    }
+
+    fn class(self) -> &'static str {
+        match self {
+            Kind::Part => "part",
+            Kind::NotPart => "not-part",
+            Kind::Gear => "gear",
+            Kind::Symbol => "symbol",
+            Kind::Empty => "",
+        }
+    }
+}
+
+const ANSI_RESET: &str = "\x1b[0m";
+
+const HTML_STYLE: &str = "body { background: #111; color: #ccc; }
+.part { color: #4c4; }
+.not-part { color: #555; }
+.gear { color: #111; background: #ec3; font-weight: bold; }
+.symbol { color: #fff; font-weight: bold; }
+.note { color: #ec3; }";
+
+struct Cells<'a> {
+    schematic: &'a Schematic,
+    gears: HashMap<usize, Gear<'a>>,
+}
+
+impl<'a> Cells<'a> {
+    fn new(schematic: &'a Schematic) -> Self {
+        let gears = schematic
+            .gears(b'*', 2)
+            .into_iter()
+            .map(|gear| (gear.index, gear))
+            .collect();
+
+        Self { schematic, gears }
+    }
+
+    fn kind(&self, i: usize) -> Kind {
+        match self.schematic.number_at(i) {
+            Some(number) if number.is_part() => Kind::Part,
+            Some(_) => Kind::NotPart,
+            None if self.gears.contains_key(&i) => Kind::Gear,
+            None if self.schematic.grid[i] == b'.' => Kind::Empty,
+            None => Kind::Symbol,
+        }
+    }
+
+    /// the cells of the row grouped into runs of the same kind, with a run to each gear
+    fn runs(&self, row: usize) -> Vec<(Kind, &'a [u8])> {
+        let columns = self.schematic.grid.columns();
+        let cells = self.schematic.grid.row_cells(row);
+        let mut runs: Vec<(Kind, &[u8])> = vec![];
+        let mut start = 0;
+
+        for column in 1..=columns {
+            let kind = self.kind(row * columns + start);
+            if column == columns || kind == Kind::Gear || self.kind(row * columns + column) != kind
+            {
+                runs.push((kind, &cells[start..column]));
+                start = column;
+            }
+        }
+
+        runs
+    }
+
+    /// the gears in the row, like `467 * 35 = 16345`
+    fn notes(&self, row: usize) -> Vec<String> {
+        let columns = self.schematic.grid.columns();
+        (row * columns..(row + 1) * columns)
+            .filter_map(|i| self.gears.get(&i))
+            .map(describe)
+            .collect()
+    }
+}
+
+fn describe(gear: &Gear) -> String {
+    let values: Vec<String> = gear
+        .numbers
+        .iter()
+        .map(|number| number.value.to_string())
+        .collect();
+    match gear.ratio {
+        Some(ratio) => format!("{} = {}", values.join(" * "), ratio),
+        None => format!("{} is too large", values.join(" * ")),
+    }
+}
+
+/// the schematic with ANSI color codes, for a terminal
+pub fn render_ansi(schematic: &Schematic) -> String {
+    let cells = Cells::new(schematic);
+    let mut rendered = String::new();
+
+    for row in 0..schematic.grid.rows() {
+        for (kind, text) in cells.runs(row) {
+            let text = String::from_utf8_lossy(text);
+            match kind {
+                Kind::Empty => rendered.push_str(&text),
+                _ => rendered.push_str(&format!("{}{}{}", kind.ansi(), text, ANSI_RESET)),
+            }
+        }
+
+        let notes = cells.notes(row);
+        if !notes.is_empty() {
+            rendered.push_str(&format!(
+                "  {}{}{}",
+                Kind::Gear.ansi(),
+                notes.join(", "),
+                ANSI_RESET
+            ));
+        }
+        rendered.push('\n');
+    }
+
+    rendered
+}
+
+/// the schematic as a standalone HTML page, where hovering over a gear shows its ratio
+pub fn render_html(schematic: &Schematic) -> String {
+    let cells = Cells::new(schematic);
+    let mut body = String::new();
+
+    for row in 0..schematic.grid.rows() {
+        let columns = schematic.grid.columns();
+        let mut column = 0;
+
+        for (kind, text) in cells.runs(row) {
+            let escaped = escape_html(&String::from_utf8_lossy(text));
+            match kind {
+                Kind::Empty => body.push_str(&escaped),
+                Kind::Gear => body.push_str(&format!(
+                    r#"<span class="{}" title="{}">{}</span>"#,
+                    kind.class(),
+                    describe(&cells.gears[&(row * columns + column)]),
+                    escaped
+                )),
+                _ => body.push_str(&format!(
+                    r#"<span class="{}">{}</span>"#,
+                    kind.class(),
+                    escaped
+                )),
+            }
+            column += text.len();
+        }
+
+        let notes = cells.notes(row);
+        if !notes.is_empty() {
+            body.push_str(&format!(
+                r#"  <span class="note">{}</span>"#,
+                notes.join(", ")
+            ));
+        }
+        body.push('\n');
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Schematic</title>\n<style>\n{}\n</style>\n</head>\n<body>\n<pre>\n{}</pre>\n</body>\n</html>\n",
+        HTML_STYLE, body
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::render::{render_ansi, render_html};
+    use crate::schematic::Schematic;
+
+    #[test]
+    fn test_render_ansi() {
+        let schematic = Schematic::new("12*3.4\n..&...\n").unwrap();
+        let side_by_side = Schematic::new("1..2\n.**.\n3..4\n").unwrap();
+
+        assert!(render_ansi(&side_by_side)
+            .contains(".\x1b[1;33m*\x1b[0m\x1b[1;33m*\x1b[0m.  \x1b[1;33m1 * 3 = 3, 2 * 4 = 8"));
+        assert_eq!(
+            render_ansi(&schematic),
+            "\x1b[32m12\x1b[0m\x1b[1;33m*\x1b[0m\x1b[32m3\x1b[0m.\x1b[2m4\x1b[0m  \x1b[1;33m12 * 3 = 36\x1b[0m\n..\x1b[1m&\x1b[0m...\n"
+        );
+    }
+
+    #[test]
+    fn test_render_html() {
+        let html = render_html(&Schematic::new("12*3.4\n..&...\n").unwrap());
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(
+            r#"<span class="part">12</span><span class="gear" title="12 * 3 = 36">*</span><span class="part">3</span>.<span class="not-part">4</span>  <span class="note">12 * 3 = 36</span>"#
+        ));
+        assert!(html.contains(r#"..<span class="symbol">&amp;</span>..."#));
+    }
+}
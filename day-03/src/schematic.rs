@@ -1,202 +1,403 @@
-pub struct Schematic {
-    pub bytes: Box<[u8]>,
-    pub columns: usize,
-    pub rows: usize,
+use common::error::Located;
+use grid::{Grid, ParseGridError};
+use std::collections::BTreeMap;
+use std::fmt;
+use ParseSchematicError::*;
+
+/// A run of digits in a row of the schematic, which is a part number if it touches a symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartNumber {
+    pub value: usize,
+    pub row: usize,
+    pub start_col: usize,
+    /// one past the column of the last digit
+    pub end_col: usize,
+    /// the indices of the symbols touching any of the digits, in order
+    pub adjacent_symbols: Vec<usize>,
 }
 
-impl Schematic {
-    pub fn new(source: &str) -> Self {
-        let columns = source.trim().find('\n').expect("at least two rows");
-        let string = source.trim().replace('\n', "");
-        let rows = string.len() / columns;
-        let bytes = string.as_bytes().into();
-
-        Self {
-            bytes,
-            columns,
-            rows,
-        }
-    }
-
-    pub fn row(&self, i: usize) -> usize {
-        i / self.columns
+impl PartNumber {
+    pub fn is_part(&self) -> bool {
+        !self.adjacent_symbols.is_empty()
     }
+}
 
-    pub fn column(&self, i: usize) -> usize {
-        i % self.columns
-    }
+pub struct Schematic {
+    pub grid: Grid<u8>,
+    /// every number in the schematic, in reading order
+    numbers: Vec<PartNumber>,
+    /// for each cell, the position in `numbers` of the number it's a digit of
+    number_cells: Vec<Option<usize>>,
+    /// for each symbol's cell, the positions in `numbers` of the numbers touching it
+    symbol_numbers: BTreeMap<usize, Vec<usize>>,
+}
 
-    pub fn adjacent_indices(&self, i: usize) -> Vec<usize> {
-        let mut adjacent_indices = vec![];
+pub fn is_symbol(byte: u8) -> bool {
+    !byte.is_ascii_digit() && byte != b'.'
+}
 
-        let can_go_up = i >= self.columns;
-        let can_go_right = self.column(i) < self.columns - 1;
-        let can_go_down = self.row(i) < self.rows - 1;
-        let can_go_left = self.column(i) > 0;
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseSchematicError {
+    Empty,
+    /// the line after the only row
+    SingleRow {
+        line: usize,
+    },
+    RaggedRow {
+        line: usize,
+        column: usize,
+        expected: usize,
+    },
+    NonAscii {
+        line: usize,
+        column: usize,
+    },
+    /// at the number's first digit
+    NumberTooLarge {
+        line: usize,
+        column: usize,
+    },
+}
 
-        if can_go_up {
-            adjacent_indices.push(i - self.columns)
-        }
-        if can_go_up && can_go_right {
-            adjacent_indices.push(i - self.columns + 1)
+impl fmt::Display for ParseSchematicError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Empty => write!(f, "expected a schematic"),
+            SingleRow { .. } => write!(f, "expected at least two rows"),
+            RaggedRow { expected, .. } => write!(f, "expected every row to be {} long", expected),
+            NonAscii { .. } => write!(f, "expected only ASCII characters"),
+            NumberTooLarge { .. } => write!(f, "expected a number no larger than {}", usize::MAX),
         }
-        if can_go_right {
-            adjacent_indices.push(i + 1)
-        }
-        if can_go_down && can_go_right {
-            adjacent_indices.push(i + self.columns + 1)
+    }
+}
+
+impl Located for ParseSchematicError {
+    fn line(&self) -> usize {
+        match self {
+            Empty => 1,
+            SingleRow { line }
+            | RaggedRow { line, .. }
+            | NonAscii { line, .. }
+            | NumberTooLarge { line, .. } => *line,
         }
-        if can_go_down {
-            adjacent_indices.push(i + self.columns)
+    }
+
+    fn column(&self) -> usize {
+        match self {
+            Empty | SingleRow { .. } => 1,
+            RaggedRow { column, .. } | NonAscii { column, .. } | NumberTooLarge { column, .. } => {
+                *column
+            }
         }
-        if can_go_down && can_go_left {
-            adjacent_indices.push(i + self.columns - 1)
+    }
+}
+
+impl From<ParseGridError> for ParseSchematicError {
+    fn from(error: ParseGridError) -> Self {
+        match error {
+            ParseGridError::Empty => Empty,
+            ParseGridError::RaggedRow {
+                line,
+                column,
+                expected,
+            } => RaggedRow {
+                line,
+                column,
+                expected,
+            },
         }
-        if can_go_left {
-            adjacent_indices.push(i - 1)
+    }
+}
+
+impl Schematic {
+    /// blank lines before and after the schematic are skipped, but errors are still
+    /// placed by the lines of the whole source
+    pub fn new(source: &str) -> Result<Self, ParseSchematicError> {
+        let skipped = source
+            .lines()
+            .take_while(|line| line.trim().is_empty())
+            .count();
+        let start: usize = source
+            .split_inclusive('\n')
+            .take(skipped)
+            .map(str::len)
+            .sum();
+        let source = source[start..].trim_end();
+
+        // before measuring rows, since their lengths are in bytes
+        for (i, line) in source.lines().enumerate() {
+            if let Some(column) = line.find(|c: char| !c.is_ascii()) {
+                return Err(NonAscii {
+                    line: skipped + i + 1,
+                    column: column + 1,
+                });
+            }
         }
-        if can_go_up && can_go_left {
-            adjacent_indices.push(i - self.columns - 1)
+
+        let grid: Grid<u8> = source.parse().map_err(|error| match error {
+            ParseGridError::RaggedRow {
+                line,
+                column,
+                expected,
+            } => RaggedRow {
+                line: skipped + line,
+                column,
+                expected,
+            },
+            error => error.into(),
+        })?;
+        if grid.rows() < 2 {
+            return Err(SingleRow { line: skipped + 2 });
         }
 
-        adjacent_indices
-    }
+        let mut numbers = vec![];
+        let mut number_cells = vec![None; grid.len()];
 
-    pub fn adjacent_to_symbol(&self, i: usize) -> bool {
-        self.adjacent_indices(i)
-            .iter()
-            .any(|&j| !self.bytes[j].is_ascii_digit() && self.bytes[j] != b'.')
-    }
+        for row in 0..grid.rows() {
+            let cells = grid.row_cells(row);
+            let mut column = 0;
 
-    pub fn part_numbers(&self) -> Vec<usize> {
-        let mut part_numbers: Vec<usize> = vec![];
-        let mut current_number_digits: Vec<usize> = vec![];
-        let mut is_adjacent = false;
-
-        for i in 0..self.bytes.len() {
-            let byte = self.bytes[i];
-
-            if self.column(i) == 0 || !byte.is_ascii_digit() {
-                if !current_number_digits.is_empty() && is_adjacent {
-                    let mut part_number = 0;
-                    let mut place = 1;
-                    for digit in current_number_digits.iter().rev() {
-                        part_number += digit * place;
-                        place *= 10;
-                    }
-                    part_numbers.push(part_number);
+            while column < cells.len() {
+                if !cells[column].is_ascii_digit() {
+                    column += 1;
+                    continue;
                 }
-                current_number_digits.clear();
-                is_adjacent = false;
-            }
 
-            if byte.is_ascii_digit() {
-                current_number_digits.push((byte - b'0') as usize);
-                if is_adjacent {
-                    continue;
+                let start_col = column;
+                while column < cells.len() && cells[column].is_ascii_digit() {
+                    column += 1;
                 }
-                if self.adjacent_to_symbol(i) {
-                    is_adjacent = true;
+
+                let first = row * grid.columns();
+                let digits = (first + start_col)..(first + column);
+                let mut adjacent_symbols: Vec<usize> = digits
+                    .clone()
+                    .flat_map(|i| grid.adjacent(i))
+                    .filter(|&j| is_symbol(grid[j]))
+                    .collect();
+                adjacent_symbols.sort_unstable();
+                adjacent_symbols.dedup();
+
+                let value = cells[start_col..column]
+                    .iter()
+                    .try_fold(0usize, |value, digit| {
+                        value.checked_mul(10)?.checked_add((digit - b'0') as usize)
+                    })
+                    .ok_or(NumberTooLarge {
+                        line: skipped + row + 1,
+                        column: start_col + 1,
+                    })?;
+
+                for i in digits {
+                    number_cells[i] = Some(numbers.len());
                 }
+                numbers.push(PartNumber {
+                    value,
+                    row,
+                    start_col,
+                    end_col: column,
+                    adjacent_symbols,
+                });
             }
         }
 
-        part_numbers
+        let mut symbol_numbers: BTreeMap<usize, Vec<usize>> = (0..grid.len())
+            .filter(|&i| is_symbol(grid[i]))
+            .map(|i| (i, vec![]))
+            .collect();
+        for (n, number) in numbers.iter().enumerate() {
+            for symbol in &number.adjacent_symbols {
+                symbol_numbers.get_mut(symbol).unwrap().push(n);
+            }
+        }
+
+        Ok(Self {
+            grid,
+            numbers,
+            number_cells,
+            symbol_numbers,
+        })
     }
 
-    pub fn part_number_at(&self, i: usize) -> usize {
-        debug_assert!(self.bytes[i].is_ascii_digit());
+    /// every number in reading order, whether or not it touches a symbol
+    pub fn numbers(&self) -> &[PartNumber] {
+        &self.numbers
+    }
 
-        let first_in_row = i - self.column(i);
-        let last_in_row = first_in_row + self.columns - 1;
+    /// the number that the cell is a digit of
+    pub fn number_at(&self, i: usize) -> Option<&PartNumber> {
+        self.number_cells[i].map(|n| &self.numbers[n])
+    }
 
-        let mut left = first_in_row;
-        let mut right = last_in_row;
+    /// the cells of every symbol, in reading order
+    pub fn symbols(&self) -> impl Iterator<Item = usize> + '_ {
+        self.symbol_numbers.keys().copied()
+    }
 
-        for l in (first_in_row..i).rev() {
-            if !self.bytes[l].is_ascii_digit() {
-                left = l + 1;
-                break;
-            }
-        }
+    /// the numbers touching the symbol in the cell, in reading order, or None if the
+    /// cell isn't a symbol; the other way round is `PartNumber::adjacent_symbols`
+    pub fn numbers_touching(&self, symbol: usize) -> Option<Vec<&PartNumber>> {
+        self.symbol_numbers
+            .get(&symbol)
+            .map(|touching| touching.iter().map(|&n| &self.numbers[n]).collect())
+    }
+
+    /// the numbers touching at least one `symbol` and no other kind of symbol
+    pub fn numbers_touching_only(&self, symbol: u8) -> Vec<&PartNumber> {
+        self.numbers
+            .iter()
+            .filter(|number| {
+                number.is_part()
+                    && number
+                        .adjacent_symbols
+                        .iter()
+                        .all(|&i| self.grid[i] == symbol)
+            })
+            .collect()
+    }
+
+    /// the cells of the symbols touching no numbers
+    pub fn lonely_symbols(&self) -> Vec<usize> {
+        self.symbol_numbers
+            .iter()
+            .filter(|(_, touching)| touching.is_empty())
+            .map(|(&i, _)| i)
+            .collect()
+    }
 
-        for r in (i + 1)..=last_in_row {
-            if !self.bytes[r].is_ascii_digit() {
-                right = r - 1;
-                break;
+    /// counts for each kind of symbol in the schematic, in byte order, from one pass over
+    /// the symbols and one over the numbers
+    pub fn symbol_stats(&self) -> Vec<SymbolStats> {
+        let mut stats: BTreeMap<u8, SymbolStats> = BTreeMap::new();
+
+        for (&i, touching) in &self.symbol_numbers {
+            let symbol = self.grid[i];
+            let entry = stats.entry(symbol).or_insert(SymbolStats {
+                symbol,
+                ..SymbolStats::default()
+            });
+
+            entry.cells += 1;
+            if touching.is_empty() {
+                entry.lonely += 1;
             }
         }
 
-        let mut part_number = 0;
-        let mut place = 1;
-
-        for j in (left..=right).rev() {
-            part_number += ((self.bytes[j] - b'0') as usize) * place;
-            place *= 10;
+        for number in &self.numbers {
+            let mut kinds: Vec<u8> = number
+                .adjacent_symbols
+                .iter()
+                .map(|&i| self.grid[i])
+                .collect();
+            kinds.sort_unstable();
+            kinds.dedup();
+
+            for kind in &kinds {
+                let entry = stats
+                    .get_mut(kind)
+                    .expect("every symbol a number touches has a cell");
+                entry.numbers += 1;
+                entry.sum += number.value as u128;
+                if kinds.len() == 1 {
+                    entry.only += 1;
+                }
+            }
         }
 
-        part_number
+        stats.into_values().collect()
     }
 
-    pub fn gear_ratios(&self) -> Vec<usize> {
-        let mut results = vec![];
-
-        for i in 0..self.bytes.len() {
-            if self.bytes[i] == b'*' {
-                let mut indices_of_adjacent_digits: Vec<usize> = self
-                    .adjacent_indices(i)
-                    .iter()
-                    .filter_map(|&j| self.bytes[j].is_ascii_digit().then_some(j))
-                    .collect();
+    pub fn adjacent_indices(&self, i: usize) -> Vec<usize> {
+        self.grid.adjacent(i).collect()
+    }
 
-                // we're looking for at least two digits adjacent to the *
-                if indices_of_adjacent_digits.len() < 2 {
-                    continue;
-                }
+    pub fn adjacent_to_symbol(&self, i: usize) -> bool {
+        self.grid.adjacent(i).any(|j| is_symbol(self.grid[j]))
+    }
 
-                indices_of_adjacent_digits.sort_unstable();
+    pub fn part_numbers(&self) -> Vec<usize> {
+        self.numbers
+            .iter()
+            .filter(|number| number.is_part())
+            .map(|number| number.value)
+            .collect()
+    }
 
-                let non_adjacent_digit_pairs: Vec<&[usize]> = indices_of_adjacent_digits
-                    .windows(2)
-                    .filter(|pair| pair[1] - pair[0] > 1)
-                    .collect();
+    pub fn part_number_at(&self, i: usize) -> usize {
+        self.number_at(i).expect("there is a number at i").value
+    }
 
-                // we're looking for exactly one pair of part numbers adjacent to the *
-                if non_adjacent_digit_pairs.len() != 1 {
-                    continue;
+    /// every `symbol` touching exactly `arity` distinct numbers, in reading order
+    pub fn gears(&self, symbol: u8, arity: usize) -> Vec<Gear<'_>> {
+        self.symbol_numbers
+            .iter()
+            .filter(|&(&i, touching)| self.grid[i] == symbol && touching.len() == arity)
+            .map(|(&i, touching)| {
+                let numbers: Vec<&PartNumber> =
+                    touching.iter().map(|&n| &self.numbers[n]).collect();
+
+                Gear {
+                    index: i,
+                    ratio: numbers
+                        .iter()
+                        .try_fold(1usize, |ratio, number| ratio.checked_mul(number.value)),
+                    numbers,
                 }
+            })
+            .collect()
+    }
 
-                let part_number_indicies = non_adjacent_digit_pairs[0];
-
-                let gear_ratio = self.part_number_at(part_number_indicies[0])
-                    * self.part_number_at(part_number_indicies[1]);
+    pub fn gear_ratios(&self) -> Vec<Option<usize>> {
+        self.gears(b'*', 2).iter().map(|gear| gear.ratio).collect()
+    }
+}
 
-                results.push(gear_ratio);
-            }
-        }
+/// How one kind of symbol sits among the numbers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SymbolStats {
+    pub symbol: u8,
+    /// how many cells hold the symbol
+    pub cells: usize,
+    /// how many of those touch no numbers
+    pub lonely: usize,
+    /// how many numbers touch the symbol
+    pub numbers: usize,
+    /// how many numbers touch the symbol and no other kind
+    pub only: usize,
+    /// the sum of the numbers touching the symbol, which has room for every number to be
+    /// as large as parsing allows
+    pub sum: u128,
+}
 
-        results
-    }
+/// A symbol touching a given number of part numbers.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Gear<'a> {
+    /// the cell the symbol is in
+    pub index: usize,
+    /// in reading order
+    pub numbers: Vec<&'a PartNumber>,
+    /// the product of the numbers, or None if it's too large for a usize
+    pub ratio: Option<usize>,
 }
 
 #[cfg(test)]
 pub mod test {
-    use crate::schematic::Schematic;
+    use crate::schematic::{ParseSchematicError::*, PartNumber, Schematic, SymbolStats};
     use std::fs;
 
     #[test]
     fn test_new() {
         let input = fs::read_to_string("test-input.txt").expect("test input exists");
 
-        let schematic = Schematic::new(&input);
-        assert_eq!(schematic.columns, 10);
-        assert_eq!(schematic.rows, 10);
+        let schematic = Schematic::new(&input).unwrap();
+        assert_eq!(schematic.grid.columns(), 10);
+        assert_eq!(schematic.grid.rows(), 10);
     }
 
     #[test]
     fn test_adjacent_indices() {
         let input = fs::read_to_string("test-input.txt").expect("test input exists");
-        let schematic = Schematic::new(&input);
+        let schematic = Schematic::new(&input).unwrap();
 
         assert_eq!(schematic.adjacent_indices(0), vec![1, 11, 10]);
         assert_eq!(schematic.adjacent_indices(1), vec![2, 12, 11, 10, 0]);
@@ -213,19 +414,19 @@ pub mod test {
     #[test]
     fn test_adjacent_to_symbol() {
         let input = fs::read_to_string("test-input.txt").expect("test input exists");
-        let schematic = Schematic::new(&input);
+        let schematic = Schematic::new(&input).unwrap();
 
-        assert_eq!(schematic.adjacent_to_symbol(0), false);
-        assert_eq!(schematic.adjacent_to_symbol(1), false);
-        assert_eq!(schematic.adjacent_to_symbol(2), true);
-        assert_eq!(schematic.adjacent_to_symbol(93), true);
-        assert_eq!(schematic.adjacent_to_symbol(99), false);
+        assert!(!schematic.adjacent_to_symbol(0));
+        assert!(!schematic.adjacent_to_symbol(1));
+        assert!(schematic.adjacent_to_symbol(2));
+        assert!(schematic.adjacent_to_symbol(93));
+        assert!(!schematic.adjacent_to_symbol(99));
     }
 
     #[test]
     fn test_part_numbers() {
         let input = fs::read_to_string("test-input.txt").expect("test input exists");
-        let schematic = Schematic::new(&input);
+        let schematic = Schematic::new(&input).unwrap();
         let part_numbers = schematic.part_numbers();
 
         assert_eq!(part_numbers, vec![467, 35, 633, 617, 592, 755, 664, 598]);
@@ -235,7 +436,7 @@ pub mod test {
     #[test]
     fn test_part_number_at() {
         let input = fs::read_to_string("test-input.txt").expect("test input exists");
-        let schematic = Schematic::new(&input);
+        let schematic = Schematic::new(&input).unwrap();
 
         assert_eq!(schematic.part_number_at(0), 467);
         assert_eq!(schematic.part_number_at(1), 467);
@@ -244,11 +445,158 @@ pub mod test {
         assert_eq!(schematic.part_number_at(97), 598);
     }
 
+    #[test]
+    fn test_numbers() {
+        let input = fs::read_to_string("test-input.txt").expect("test input exists");
+        let schematic = Schematic::new(&input).unwrap();
+
+        assert_eq!(schematic.numbers().len(), 10);
+        assert_eq!(
+            schematic.number_at(23),
+            Some(&PartNumber {
+                value: 35,
+                row: 2,
+                start_col: 2,
+                end_col: 4,
+                adjacent_symbols: vec![13]
+            })
+        );
+        assert_eq!(
+            schematic.number_at(5).map(|number| number.is_part()),
+            Some(false)
+        );
+        assert_eq!(schematic.number_at(4), None);
+        assert_eq!(schematic.number_at(93).unwrap().adjacent_symbols, vec![83]);
+    }
+
     #[test]
     fn test_gear_ratios() {
         let input = fs::read_to_string("test-input.txt").expect("test input exists");
-        let schematic = Schematic::new(&input);
+        let schematic = Schematic::new(&input).unwrap();
+
+        assert_eq!(schematic.gear_ratios(), vec![Some(16345), Some(451490)]);
+    }
+
+    #[test]
+    fn test_gears() {
+        let schematic = Schematic::new("1.2.3\n.#*#.\n4...5\n").unwrap();
 
-        assert_eq!(schematic.gear_ratios(), vec![16345, 451490]);
+        let gears = schematic.gears(b'#', 3);
+        assert_eq!(gears.len(), 2);
+        assert_eq!(gears[0].index, 6);
+        assert_eq!(
+            gears[0]
+                .numbers
+                .iter()
+                .map(|number| number.value)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 4]
+        );
+        assert_eq!(gears[1].ratio, Some(2 * 3 * 5));
+
+        assert_eq!(schematic.gears(b'*', 2), vec![]);
+        assert_eq!(schematic.gears(b'*', 1)[0].ratio, Some(2));
+        assert_eq!(schematic.gears(b'#', 2), vec![]);
+
+        let large = Schematic::new(
+            "4294967296.4294967296
+..........*..........
+",
+        )
+        .unwrap();
+        assert_eq!(large.gears(b'*', 2)[0].ratio, None);
+    }
+
+    #[test]
+    fn test_symbol_index() {
+        let schematic = Schematic::new("12$.%\n.$...\n3*..4\n").unwrap();
+        let values = |numbers: Vec<&PartNumber>| {
+            numbers
+                .iter()
+                .map(|number| number.value)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(schematic.symbols().collect::<Vec<_>>(), vec![2, 4, 6, 11]);
+        assert_eq!(schematic.numbers_touching(6).map(values), Some(vec![12, 3]));
+        assert_eq!(schematic.numbers_touching(4).map(values), Some(vec![]));
+        assert_eq!(schematic.numbers_touching(0), None);
+        assert_eq!(values(schematic.numbers_touching_only(b'$')), vec![12]);
+        assert_eq!(values(schematic.numbers_touching_only(b'*')), vec![]);
+        assert_eq!(schematic.lonely_symbols(), vec![4]);
+
+        let stats = schematic.symbol_stats();
+        assert_eq!(
+            stats.iter().map(|stats| stats.symbol).collect::<Vec<_>>(),
+            b"$%*"
+        );
+        assert_eq!(
+            stats[0],
+            SymbolStats {
+                symbol: b'$',
+                cells: 2,
+                lonely: 0,
+                numbers: 2,
+                only: 1,
+                sum: 15
+            }
+        );
+        assert_eq!(
+            (stats[1].cells, stats[1].lonely, stats[1].numbers),
+            (1, 1, 0)
+        );
+        assert_eq!((stats[2].numbers, stats[2].only, stats[2].sum), (1, 0, 3));
+    }
+
+    #[test]
+    fn test_new_errors() {
+        assert_eq!(Schematic::new(" \n").err(), Some(Empty));
+        assert_eq!(
+            Schematic::new("467..114..\n").err(),
+            Some(SingleRow { line: 2 })
+        );
+        assert_eq!(
+            Schematic::new("467..\n...*\n.35..").err(),
+            Some(RaggedRow {
+                line: 2,
+                column: 5,
+                expected: 5
+            })
+        );
+        assert_eq!(
+            Schematic::new("467..\n..é*.\n").err(),
+            Some(NonAscii { line: 2, column: 3 })
+        );
+
+        // leading blank lines are skipped, and errors after them counted from the top
+        let schematic = Schematic::new("\n  \n467..\n...*.\n").unwrap();
+        assert_eq!(schematic.grid.rows(), 2);
+        assert_eq!(
+            Schematic::new("\n467..\n...*\n").err(),
+            Some(RaggedRow {
+                line: 3,
+                column: 5,
+                expected: 5
+            })
+        );
+        assert_eq!(
+            Schematic::new("\n\n467..\n").err(),
+            Some(SingleRow { line: 4 })
+        );
+        assert_eq!(
+            Schematic::new("\n..é*.\n").err(),
+            Some(NonAscii { line: 2, column: 3 })
+        );
+        assert_eq!(
+            Schematic::new("\n1.....................\n.*99999999999999999999").err(),
+            Some(NumberTooLarge { line: 3, column: 3 })
+        );
+        assert_eq!(
+            Schematic::new("18446744073709551615\n*...................")
+                .unwrap()
+                .numbers()[0]
+                .value,
+            usize::MAX
+        );
     }
 }
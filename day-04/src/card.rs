@@ -1,6 +1,8 @@
-use regex::Regex;
+use common::error::{column_of, Located};
 use std::collections::HashSet;
+use std::fmt;
 use std::str::FromStr;
+use ParseCardError::*;
 
 #[derive(Debug)]
 pub struct Card {
@@ -21,37 +23,107 @@ impl Card {
     }
 }
 
-#[derive(Debug)]
-pub struct ParseCardError;
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseCardError {
+    MissingColon { column: usize },
+    InvalidId { column: usize },
+    MissingSeparator { column: usize },
+    InvalidNumber { column: usize },
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MissingColon { .. } => write!(f, "expected ':' after the card id"),
+            InvalidId { .. } => write!(f, "expected a card id"),
+            MissingSeparator { .. } => write!(f, "expected '|' after the winning numbers"),
+            InvalidNumber { .. } => write!(f, "expected a number"),
+        }
+    }
+}
+
+impl Located for ParseCardError {
+    fn column(&self) -> usize {
+        match self {
+            MissingColon { column }
+            | InvalidId { column }
+            | MissingSeparator { column }
+            | InvalidNumber { column } => *column,
+        }
+    }
+}
 
 impl FromStr for Card {
     type Err = ParseCardError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let re = Regex::new(r"(\d+)").unwrap();
-
-        let mut parts = s.split('|');
-
-        let before_pipe_numbers = re
-            .find_iter(parts.next().unwrap())
-            .map(|m| m.as_str().parse::<usize>().unwrap());
-
-        let winners = before_pipe_numbers.skip(1).collect();
-
-        let ours = re
-            .find_iter(parts.next().unwrap())
-            .map(|m| m.as_str().parse::<usize>().unwrap())
-            .collect();
+        let (card, numbers) = s.split_once(':').ok_or(MissingColon {
+            column: s.len() + 1,
+        })?;
+
+        let id = card.split_whitespace().next_back().unwrap_or(card);
+        id.parse::<usize>().map_err(|_| InvalidId {
+            column: column_of(s, id),
+        })?;
+
+        let (winners, ours) = numbers.split_once('|').ok_or(MissingSeparator {
+            column: s.len() + 1,
+        })?;
+
+        let parse_numbers = |numbers: &str| {
+            numbers
+                .split_whitespace()
+                .map(|n| {
+                    n.parse::<usize>().map_err(|_| InvalidNumber {
+                        column: column_of(s, n),
+                    })
+                })
+                .collect::<Result<HashSet<usize>, _>>()
+        };
 
-        Ok(Self { winners, ours })
+        Ok(Self {
+            winners: parse_numbers(winners)?,
+            ours: parse_numbers(ours)?,
+        })
     }
 }
 
 #[cfg(test)]
 pub mod tests {
-    use crate::card::Card;
+    use crate::card::{Card, ParseCardError::*};
     use std::collections::HashSet;
 
+    #[test]
+    fn test_parse() {
+        let card: Card = "Card   1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"
+            .parse()
+            .unwrap();
+
+        assert_eq!(card.winners, HashSet::from([41, 48, 83, 86, 17]));
+        assert_eq!(card.ours, HashSet::from([83, 86, 6, 31, 17, 9, 48, 53]));
+        assert_eq!(card.matches(), 4);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "Card 1 41 48 | 83".parse::<Card>().unwrap_err(),
+            MissingColon { column: 18 }
+        );
+        assert_eq!(
+            "Card x: 41 48 | 83".parse::<Card>().unwrap_err(),
+            InvalidId { column: 6 }
+        );
+        assert_eq!(
+            "Card 1: 41 48 83".parse::<Card>().unwrap_err(),
+            MissingSeparator { column: 17 }
+        );
+        assert_eq!(
+            "Card 1: 41 4a | 83".parse::<Card>().unwrap_err(),
+            InvalidNumber { column: 12 }
+        );
+    }
+
     #[test]
     fn test_score() {
         let zero_matches = Card {
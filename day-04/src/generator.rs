@@ -0,0 +1,94 @@
+// Scratchcards with numbers from 1 to 99. Most cards have no matches and the rest only
+// a few, since part two's card counts grow exponentially once cards average one match
+// or more, and would soon overflow on a large pile.
+
+use crate::Day04;
+use common::generator::Generator;
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+/// There are only 99 numbers to draw from, so a card can't have more than that in all.
+pub struct Params {
+    pub cards: usize,
+    pub winning_numbers: usize,
+    pub numbers: usize,
+    pub max_matches: usize,
+}
+
+impl Generator for Day04 {
+    type Params = Params;
+
+    fn params(size: usize) -> Self::Params {
+        Params {
+            cards: size,
+            winning_numbers: 10,
+            numbers: 25,
+            max_matches: 4,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, params: &Self::Params) -> String {
+        let id_width = params.cards.to_string().len();
+        let mut pile = String::new();
+
+        for id in 1..=params.cards {
+            // a card's matches may only win copies of cards that are in the pile
+            let matches = if rng.gen_ratio(1, 4) {
+                rng.gen_range(1..=params.max_matches)
+                    .min(params.winning_numbers)
+                    .min(params.numbers)
+                    .min(params.cards - id)
+            } else {
+                0
+            };
+
+            let mut all: Vec<usize> =
+                index::sample(rng, 99, params.winning_numbers + params.numbers - matches)
+                    .into_iter()
+                    .map(|i| i + 1)
+                    .collect();
+            let others = all.split_off(params.winning_numbers);
+            let winning = all;
+
+            let mut numbers: Vec<usize> = winning[..matches].to_vec();
+            numbers.extend(others);
+            numbers.shuffle(rng);
+
+            pile.push_str(&format!(
+                "Card {:>width$}: {} | {}\n",
+                id,
+                format_numbers(&winning),
+                format_numbers(&numbers),
+                width = id_width
+            ));
+        }
+
+        pile
+    }
+}
+
+fn format_numbers(numbers: &[usize]) -> String {
+    numbers
+        .iter()
+        .map(|n| format!("{:>2}", n))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::Day04;
+    use common::generator::generate;
+    use common::solution::solve;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day04>(4, 1000);
+
+        assert_eq!(input, generate::<Day04>(4, 1000));
+        assert_eq!(input.lines().count(), 1000);
+        assert!(input.starts_with("Card    1: "));
+        assert!(solve::<Day04>(&input, None).is_ok());
+    }
+}
@@ -1 +1,34 @@
 pub mod card;
+pub mod generator;
+
+use card::Card;
+use common::error::{parse_lines, ParseError};
+use common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_one(cards: &Self::Input) -> Answer {
+        cards.iter().map(|card| card.score()).sum::<usize>().into()
+    }
+
+    fn part_two(cards: &Self::Input) -> Answer {
+        let mut card_counts: HashMap<usize, usize> =
+            HashMap::from_iter((0..cards.len()).map(|i| (i, 1)));
+
+        for (i, card) in cards.iter().enumerate() {
+            for j in (i + 1)..=(i + card.matches()) {
+                *card_counts.entry(j).or_insert(1) += card_counts[&i];
+            }
+        }
+
+        card_counts.into_values().sum::<usize>().into()
+    }
+}
@@ -1,34 +1,44 @@
 // Advent of Code 2023: Day 4
 // https://adventofcode.com/2023/day/4
-// Usage: `cargo run <input-file>
+// Usage: `cargo run [--format text|json] (<input-file>|-)...
 
-use day_04::card::Card;
-use std::collections::HashMap;
+use common::input;
+use common::report::{self, Format};
+use common::solution::Solution;
+use day_04::Day04;
 use std::env;
-use std::fs;
+use std::process;
 
 fn main() {
-    let input_filename = env::args().nth(1).expect("please supply an input filename");
-    let input = fs::read_to_string(input_filename).expect("failed to read input");
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let format = report::take_format(&mut args).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(2);
+    });
+    let sources = input::sources(args).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(2);
+    });
 
-    let cards: Vec<Card> = input
-        .trim()
-        .split('\n')
-        .map(|line| line.parse().unwrap())
-        .collect();
-
-    let mut card_counts: HashMap<usize, usize> =
-        HashMap::from_iter((0..cards.len()).map(|i| (i, 1)));
-
-    for (i, card) in cards.iter().enumerate() {
-        for j in (i + 1)..=(i + card.matches()) {
-            *card_counts.entry(j).or_insert(1) += card_counts[&i];
+    let succeeded = input::for_each(&sources, format, |source, input| {
+        if format == Format::Json {
+            return report::print_records::<Day04>(4, source, input, None);
         }
-    }
 
-    let total: usize = cards.iter().map(|card| card.score()).sum();
-    let card_count: usize = card_counts.into_values().sum();
+        let cards = Day04::parse(input)?;
 
-    println!("The total score of all the cards was {}", total);
-    println!("The total cards accumulated was {}", card_count);
+        println!(
+            "The total score of all the cards was {}",
+            Day04::part_one(&cards)
+        );
+        println!(
+            "The total cards accumulated was {}",
+            Day04::part_two(&cards)
+        );
+        Ok(())
+    });
+
+    if !succeeded {
+        process::exit(1);
+    }
 }
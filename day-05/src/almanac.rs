@@ -0,0 +1,121 @@
+use crate::map::Map;
+use common::error::{column_of, ParseError};
+use std::ops::RangeInclusive;
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub maps: Vec<Map>,
+}
+
+impl Almanac {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let mut sections = input.trim_end().split("\n\n");
+
+        let seeds_line = sections.next().unwrap_or("");
+        let seeds_list =
+            seeds_line
+                .strip_prefix("seeds:")
+                .ok_or(ParseError::new(1, 1, "expected 'seeds:'"))?;
+
+        let seeds: Vec<usize> = seeds_list
+            .split_whitespace()
+            .map(|seed| {
+                seed.parse().map_err(|_| {
+                    ParseError::new(1, column_of(seeds_line, seed), "expected a seed number")
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        if seeds.is_empty() {
+            return Err(ParseError::new(
+                1,
+                seeds_line.len() + 1,
+                "expected at least one seed",
+            ));
+        }
+
+        let mut maps = vec![];
+        // the line each section starts on, counting the blank line before it
+        let mut line = seeds_line.lines().count() + 2;
+
+        for section in sections {
+            let map: Map = section
+                .parse()
+                .map_err(|error| ParseError::at_line(line, &error))?;
+            maps.push(map);
+            line += section.lines().count() + 1;
+        }
+
+        Ok(Self { seeds, maps })
+    }
+
+    /// the seeds line read as pairs of range start and range length, as part two does, or
+    /// None if it isn't pairs of a start and a nonzero length ending within a usize; part
+    /// one reads the same line as a plain list, so parsing leaves this to part two
+    pub fn seed_ranges(&self) -> Option<Vec<RangeInclusive<usize>>> {
+        if self.seeds.len() % 2 == 1 {
+            return None;
+        }
+
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| {
+                let last = pair[0].checked_add(pair[1].checked_sub(1)?)?;
+                Some(pair[0]..=last)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::almanac::Almanac;
+    use common::error::ParseError;
+
+    #[test]
+    fn test_new() {
+        let text = "seeds: 79 14 55 13\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nsoil-to-fertilizer map:\n0 15 37\n";
+        let almanac = Almanac::new(text).unwrap();
+
+        assert_eq!(almanac.seeds, vec![79, 14, 55, 13]);
+        assert_eq!(almanac.seed_ranges(), Some(vec![79..=92, 55..=67]));
+        assert_eq!(almanac.maps.len(), 2);
+        assert_eq!(almanac.maps[1].from, "soil");
+    }
+
+    #[test]
+    fn test_seed_ranges() {
+        let ranges = |seeds: &str| {
+            Almanac::new(&format!("seeds: {}\n\nseed-to-soil map:\n50 98 2\n", seeds))
+                .unwrap()
+                .seed_ranges()
+        };
+
+        assert_eq!(ranges("5 1"), Some(vec![5..=5]));
+        assert_eq!(ranges("1"), None);
+        assert_eq!(ranges("79 14 55 0"), None);
+        assert_eq!(
+            ranges(&format!("{} 2", usize::MAX - 1)),
+            Some(vec![usize::MAX - 1..=usize::MAX])
+        );
+        assert_eq!(ranges(&format!("{} 3", usize::MAX - 1)), None);
+    }
+
+    #[test]
+    fn test_new_errors() {
+        assert_eq!(
+            Almanac::new("seeds: 79 x4").unwrap_err(),
+            ParseError::new(1, 11, "expected a seed number")
+        );
+        let text = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n\nsoil-to-fertilizer map:\n0 15\n";
+        assert_eq!(
+            Almanac::new(text).unwrap_err(),
+            ParseError::new(
+                7,
+                5,
+                "expected exactly three numbers: destination start, source start and length"
+            )
+        );
+    }
+}
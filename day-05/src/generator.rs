@@ -0,0 +1,140 @@
+// Almanacs with the usual chain of seven maps. Each map shuffles the chunks of a
+// partition of 0..span and leaves everything past the span alone, then leaves out about
+// one chunk in four. The values in the gaps stay as they are, so they land on top of
+// the values some other chunk was moved to, and the maps try both the fall-through
+// for unmapped values and destinations overlapping other ranges' boundaries.
+
+use crate::Day05;
+use common::generator::Generator;
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+pub struct Params {
+    pub seed_ranges: usize,
+    pub ranges_per_map: usize,
+    /// the values at or beyond which every map leaves a value as it is
+    pub span: usize,
+}
+
+impl Generator for Day05 {
+    type Params = Params;
+
+    fn params(size: usize) -> Self::Params {
+        Params {
+            seed_ranges: (size / 3).max(1),
+            ranges_per_map: size.max(1),
+            span: 1 << 32,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, params: &Self::Params) -> String {
+        let span = params.span;
+        let most_seeds = (span / params.seed_ranges).max(1);
+
+        let seeds: Vec<String> = (0..params.seed_ranges)
+            .flat_map(|_| {
+                let start = rng.gen_range(0..span);
+                let length = rng.gen_range(1..=(span - start).min(most_seeds));
+                [start.to_string(), length.to_string()]
+            })
+            .collect();
+
+        let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+
+        for names in CATEGORIES.windows(2) {
+            almanac.push_str(&format!("\n{}-to-{} map:\n", names[0], names[1]));
+
+            let ranges = shuffled_chunks(rng, span, params.ranges_per_map);
+            let kept = ranges.len() - ranges.len() / 4;
+            for (destination, source, length) in ranges.into_iter().take(kept) {
+                almanac.push_str(&format!("{} {} {}\n", destination, source, length));
+            }
+        }
+
+        almanac
+    }
+}
+
+/// split 0..span into chunks and move each one to where it lands when the chunks are
+/// put in a random order, as (destination start, source start, length) in random order
+fn shuffled_chunks(rng: &mut StdRng, span: usize, chunks: usize) -> Vec<(usize, usize, usize)> {
+    let chunks = chunks.min(span);
+    let mut starts: Vec<usize> = index::sample(rng, span - 1, chunks - 1)
+        .into_iter()
+        .map(|i| i + 1)
+        .collect();
+    starts.push(0);
+    starts.sort();
+
+    let mut sources: Vec<(usize, usize)> = starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| (start, starts.get(i + 1).unwrap_or(&span) - start))
+        .collect();
+    sources.shuffle(rng);
+
+    let mut destination = 0;
+    let mut ranges: Vec<(usize, usize, usize)> = sources
+        .into_iter()
+        .map(|(source, length)| {
+            destination += length;
+            (destination - length, source, length)
+        })
+        .collect();
+    ranges.shuffle(rng);
+
+    ranges
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::generator::shuffled_chunks;
+    use crate::Day05;
+    use common::generator::generate;
+    use common::solution::solve;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day05>(5, 30);
+
+        assert_eq!(input, generate::<Day05>(5, 30));
+        assert!(input.starts_with("seeds: "));
+        assert_eq!(input.matches("map:").count(), 7);
+        assert!(solve::<Day05>(&input, None).is_ok());
+    }
+
+    #[test]
+    fn test_shuffled_chunks() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut ranges = shuffled_chunks(&mut rng, 100, 8);
+        assert_eq!(ranges.len(), 8);
+
+        ranges.sort_by_key(|&(_, source, _)| source);
+        assert_eq!(
+            ranges.iter().map(|&(_, _, length)| length).sum::<usize>(),
+            100
+        );
+        assert!(ranges
+            .windows(2)
+            .all(|pair| pair[0].1 + pair[0].2 == pair[1].1));
+
+        ranges.sort();
+        assert!(ranges
+            .windows(2)
+            .all(|pair| pair[0].0 + pair[0].2 == pair[1].0));
+    }
+}
@@ -1 +1,69 @@
+// credit for the part two algorithm goes to reddit user zuleyorker.
+
+pub mod almanac;
+pub mod generator;
 pub mod map;
+pub mod reference;
+
+use almanac::Almanac;
+use common::error::ParseError;
+use common::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Almanac::new(input)
+    }
+
+    fn part_one(almanac: &Self::Input) -> Answer {
+        let locations = almanac
+            .maps
+            .iter()
+            .fold(almanac.seeds.clone(), |seeds, map| {
+                seeds.iter().map(|&seed| map.get(seed)).collect()
+            });
+
+        (*locations.iter().min().unwrap()).into()
+    }
+
+    fn part_two(almanac: &Self::Input) -> Answer {
+        let Some(seed_ranges) = almanac.seed_ranges() else {
+            return Answer::Unanswerable;
+        };
+        let maps = &almanac.maps;
+
+        // try to find a smaller number of seeds to check by working backward through the maps
+        let mut seeds_to_check = maps
+            .iter()
+            .rev()
+            // start with the endpoints of the whole usize range
+            .fold(HashSet::from([0, usize::MAX]), |set, map| {
+                // for each map working backward
+                // translate each current value back to every value the map sends to it
+                let mut updated: HashSet<_> = set
+                    .iter()
+                    .flat_map(|&value| map.get_reverse(value))
+                    .collect();
+                // then add the endpoints from the map's source range
+                updated.extend(map.source_range_endpoints());
+                updated
+            });
+
+        // limit the endpoints to check to values in the known seed ranges
+        seeds_to_check.retain(|value| seed_ranges.iter().any(|range| range.contains(value)));
+
+        // add the endpoints of the seed ranges themselves
+        seeds_to_check.extend(seed_ranges.iter().flat_map(|r| vec![r.start(), r.end()]));
+
+        // now that we have a limited number of seeds to check, translate to locations and get the min
+        let locations = maps.iter().fold(seeds_to_check, |results, map| {
+            results.iter().map(|&source| map.get(source)).collect()
+        });
+
+        (*locations.iter().min().unwrap()).into()
+    }
+}
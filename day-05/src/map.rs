@@ -1,7 +1,10 @@
+use common::error::{column_of, Located};
 use regex::Regex;
 use std::collections::HashSet;
+use std::fmt;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
+use ParseMapError::*;
 
 #[derive(Debug)]
 pub struct Map {
@@ -24,16 +27,28 @@ impl Map {
         source
     }
 
-    pub fn get_reverse(&self, destination: usize) -> usize {
-        for range_i in 0..self.destination_ranges.len() {
-            let source_range = &self.source_ranges[range_i];
-            let destination_range = &self.destination_ranges[range_i];
+    /// every source the map sends to the destination: one for each range moving a value
+    /// onto it, and the destination itself if no range moves it away; maps with gaps can
+    /// send several values to the same place, or none
+    pub fn get_reverse(&self, destination: usize) -> Vec<usize> {
+        let mut sources: Vec<usize> = self
+            .source_ranges
+            .iter()
+            .zip(&self.destination_ranges)
+            .filter(|(_, destination_range)| destination_range.contains(&destination))
+            .map(|(source_range, destination_range)| {
+                source_range.start() + (destination - destination_range.start())
+            })
+            .collect();
 
-            if destination_range.contains(&destination) {
-                return source_range.start() + (destination - destination_range.start());
-            }
+        if !self
+            .source_ranges
+            .iter()
+            .any(|source_range| source_range.contains(&destination))
+        {
+            sources.push(destination);
         }
-        destination
+        sources
     }
 
     /// return a set containing the start and end of each range,
@@ -58,32 +73,106 @@ impl Map {
     }
 }
 
-#[derive(Debug)]
-pub struct ParseMapError;
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseMapError {
+    InvalidName { line: usize, column: usize },
+    InvalidNumber { line: usize, column: usize },
+    WrongNumberCount { line: usize, column: usize },
+    EmptyRange { line: usize, column: usize },
+    RangeOverflow { line: usize, column: usize },
+}
+
+impl fmt::Display for ParseMapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InvalidName { .. } => write!(f, "expected a map name like 'seed-to-soil map:'"),
+            InvalidNumber { .. } => write!(f, "expected a number"),
+            WrongNumberCount { .. } => write!(
+                f,
+                "expected exactly three numbers: destination start, source start and length"
+            ),
+            EmptyRange { .. } => write!(f, "expected a range length greater than zero"),
+            RangeOverflow { .. } => write!(f, "expected a range that fits in a usize"),
+        }
+    }
+}
+
+impl Located for ParseMapError {
+    fn line(&self) -> usize {
+        match self {
+            InvalidName { line, .. }
+            | InvalidNumber { line, .. }
+            | WrongNumberCount { line, .. }
+            | EmptyRange { line, .. }
+            | RangeOverflow { line, .. } => *line,
+        }
+    }
+
+    fn column(&self) -> usize {
+        match self {
+            InvalidName { column, .. }
+            | InvalidNumber { column, .. }
+            | WrongNumberCount { column, .. }
+            | EmptyRange { column, .. }
+            | RangeOverflow { column, .. } => *column,
+        }
+    }
+}
 
 impl FromStr for Map {
     type Err = ParseMapError;
 
     fn from_str(s: &str) -> Result<Map, Self::Err> {
-        let name_re = Regex::new(r"(?<from>.+)-to-(?<to>.+) map:").map_err(|_| ParseMapError)?;
+        let name_re = Regex::new(r"^(?<from>.+)-to-(?<to>.+) map:$").unwrap();
 
-        let mut lines = s.split('\n');
-        let name_parts = name_re.captures(lines.next().unwrap()).unwrap();
+        let mut lines = s.lines();
+        let name_parts = name_re
+            .captures(lines.next().unwrap_or(""))
+            .ok_or(InvalidName { line: 1, column: 1 })?;
         let from = String::from(&name_parts["from"]);
         let to = String::from(&name_parts["to"]);
 
         let mut source_ranges = vec![];
         let mut destination_ranges = vec![];
 
-        for line in lines {
-            let numbers: Vec<_> = line
-                .trim()
-                .split(' ')
-                .map(|n| n.parse::<usize>().unwrap())
-                .collect();
+        for (i, line) in lines.enumerate() {
+            let line_number = i + 2;
+            let words: Vec<&str> = line.split_whitespace().collect();
+
+            if words.len() != 3 {
+                return Err(WrongNumberCount {
+                    line: line_number,
+                    column: words.get(3).map_or(line.len() + 1, |w| column_of(line, w)),
+                });
+            }
+
+            let mut numbers = [0usize; 3];
+            for (number, word) in numbers.iter_mut().zip(&words) {
+                *number = word.parse().map_err(|_| InvalidNumber {
+                    line: line_number,
+                    column: column_of(line, word),
+                })?;
+            }
+
+            let [destination, source, length] = numbers;
+            let length_column = column_of(line, words[2]);
+
+            if length == 0 {
+                return Err(EmptyRange {
+                    line: line_number,
+                    column: length_column,
+                });
+            }
+
+            let overflow = || RangeOverflow {
+                line: line_number,
+                column: length_column,
+            };
+            let source_end = source.checked_add(length - 1).ok_or_else(overflow)?;
+            let destination_end = destination.checked_add(length - 1).ok_or_else(overflow)?;
 
-            source_ranges.push(numbers[1]..=(numbers[1] + numbers[2] - 1));
-            destination_ranges.push(numbers[0]..=(numbers[0] + numbers[2] - 1));
+            source_ranges.push(source..=source_end);
+            destination_ranges.push(destination..=destination_end);
         }
 
         Ok(Map {
@@ -97,7 +186,7 @@ impl FromStr for Map {
 
 #[cfg(test)]
 pub mod tests {
-    use crate::map::Map;
+    use crate::map::{Map, ParseMapError::*};
     use std::collections::HashSet;
 
     #[test]
@@ -111,6 +200,32 @@ pub mod tests {
         assert_eq!(map.destination_ranges, vec![50..=51, 52..=99]);
     }
 
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "seed to soil\n50 98 2".parse::<Map>().unwrap_err(),
+            InvalidName { line: 1, column: 1 }
+        );
+        assert_eq!(
+            "seed-to-soil map:\n50 98 2\n52 5O 48"
+                .parse::<Map>()
+                .unwrap_err(),
+            InvalidNumber { line: 3, column: 4 }
+        );
+        assert_eq!(
+            "seed-to-soil map:\n50 98".parse::<Map>().unwrap_err(),
+            WrongNumberCount { line: 2, column: 6 }
+        );
+        assert_eq!(
+            "seed-to-soil map:\n50 98 2 7".parse::<Map>().unwrap_err(),
+            WrongNumberCount { line: 2, column: 9 }
+        );
+        assert_eq!(
+            "seed-to-soil map:\n50 98 0".parse::<Map>().unwrap_err(),
+            EmptyRange { line: 2, column: 7 }
+        );
+    }
+
     #[test]
     fn test_get() {
         let text = "seed-to-soil map:\n50 98 2\n52 50 48";
@@ -128,11 +243,18 @@ pub mod tests {
         let text = "seed-to-soil map:\n50 98 2\n52 50 48";
         let map: Map = text.parse().unwrap();
 
-        assert_eq!(map.get_reverse(50), 98);
-        assert_eq!(map.get_reverse(51), 99);
-        assert_eq!(map.get_reverse(52), 50);
-        assert_eq!(map.get_reverse(57), 55);
-        assert_eq!(map.get_reverse(99), 97);
+        assert_eq!(map.get_reverse(50), [98]);
+        assert_eq!(map.get_reverse(51), [99]);
+        assert_eq!(map.get_reverse(52), [50]);
+        assert_eq!(map.get_reverse(57), [55]);
+        assert_eq!(map.get_reverse(99), [97]);
+        assert_eq!(map.get_reverse(10), [10]);
+
+        // 12 is both where 2 moves to and a value the map leaves alone, and nothing
+        // lands on 2
+        let gaps: Map = "seed-to-soil map:\n10 0 5".parse().unwrap();
+        assert_eq!(gaps.get_reverse(12), [2, 12]);
+        assert_eq!(gaps.get_reverse(2), []);
     }
 
     #[test]
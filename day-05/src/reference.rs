@@ -0,0 +1,59 @@
+// Part two without the endpoint search: map every seed in every range to its location.
+
+use crate::almanac::Almanac;
+use crate::generator::Params;
+use crate::Day05;
+use common::reference::Reference;
+use common::solution::{Answer, Part};
+
+impl Reference for Day05 {
+    /// a span of a hundred values per seed range keeps the seeds to map in the thousands
+    fn reference_params(size: usize) -> Self::Params {
+        Params {
+            seed_ranges: size.max(1),
+            ranges_per_map: size.max(1),
+            span: 100 * size.max(1),
+        }
+    }
+
+    fn reference(part: Part, almanac: &Self::Input) -> Option<Answer> {
+        match part {
+            Part::One => None,
+            Part::Two => Some(
+                lowest_location_of_every_seed(almanac).map_or(Answer::Unanswerable, Answer::from),
+            ),
+        }
+    }
+}
+
+/// None if the seeds aren't ranges, like part two
+pub fn lowest_location_of_every_seed(almanac: &Almanac) -> Option<usize> {
+    almanac
+        .seed_ranges()?
+        .into_iter()
+        .flatten()
+        .map(|seed| almanac.maps.iter().fold(seed, |value, map| map.get(value)))
+        .min()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::almanac::Almanac;
+    use crate::reference::lowest_location_of_every_seed;
+    use crate::Day05;
+    use common::reference::differential;
+    use std::fs;
+
+    #[test]
+    fn test_lowest_location_of_every_seed() {
+        let input = fs::read_to_string("test-input.txt").unwrap();
+        let almanac = Almanac::new(&input).unwrap();
+
+        assert_eq!(lowest_location_of_every_seed(&almanac), Some(46));
+    }
+
+    #[test]
+    fn test_differential() {
+        assert!(differential::<Day05>(50, 10).is_none());
+    }
+}
@@ -0,0 +1,110 @@
+// Race sheets. Part two reads each line as one long number, so the times together
+// have at most nine digits to keep its distances within a usize. As on the real
+// sheets no record is zero, and every record, for the races apart and run together,
+// can still be beaten. Exact records are the distance some hold time goes, often a
+// very short one, so that the holds which just beat them are whole numbers right
+// next to the ends of the race, where rounding is most likely to land on the wrong one.
+
+use crate::race::distance;
+use crate::Day06;
+use common::generator::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+pub struct Params {
+    pub races: usize,
+    pub time_digits: u32,
+    pub exact_records: bool,
+}
+
+impl Generator for Day06 {
+    type Params = Params;
+
+    fn params(size: usize) -> Self::Params {
+        let races = size.clamp(1, 9);
+
+        Params {
+            races,
+            time_digits: 9 / races as u32,
+            exact_records: false,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, params: &Self::Params) -> String {
+        let lowest = 10usize.pow(params.time_digits - 1).max(3);
+        let highest = 10usize.pow(params.time_digits);
+
+        loop {
+            let times: Vec<usize> = (0..params.races)
+                .map(|_| rng.gen_range(lowest..highest))
+                .collect();
+            let records: Vec<usize> = times
+                .iter()
+                .map(|&time| {
+                    if params.exact_records {
+                        // any hold short of the best, and a third of the time one of the shortest
+                        let longest = (time - 1) / 2;
+                        let shortest = rng.gen_ratio(1, 3);
+                        let hold =
+                            rng.gen_range(0..if shortest { longest.min(3) } else { longest });
+                        distance(time, hold).max(1)
+                    } else {
+                        rng.gen_range(1..best_distance(time))
+                    }
+                })
+                .collect();
+
+            let time: usize = concatenate(&times).parse().unwrap();
+            let record: usize = concatenate(&records).parse().unwrap();
+
+            if record < best_distance(time) {
+                return format!(
+                    "Time:     {}\nDistance: {}\n",
+                    pad(&times, &records),
+                    pad(&records, &times)
+                );
+            }
+        }
+    }
+}
+
+fn best_distance(time: usize) -> usize {
+    distance(time, time / 2)
+}
+
+fn concatenate(numbers: &[usize]) -> String {
+    numbers.iter().map(usize::to_string).collect()
+}
+
+/// right align each number with its counterpart on the other line
+fn pad(numbers: &[usize], others: &[usize]) -> String {
+    numbers
+        .iter()
+        .zip(others)
+        .map(|(n, other)| {
+            let width = n.to_string().len().max(other.to_string().len());
+            format!("{:>width$}", n, width = width)
+        })
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::Day06;
+    use common::generator::{generate, Generator};
+    use common::solution::{solve, Solution};
+
+    #[test]
+    fn test_generate() {
+        for size in 1..=12usize {
+            let input = generate::<Day06>(size as u64, size);
+            let sheet = Day06::parse(&input).unwrap();
+
+            assert_eq!(sheet.races.len(), Day06::params(size).races);
+            assert!(solve::<Day06>(&input, None).is_ok());
+        }
+
+        assert_eq!(generate::<Day06>(6, 4), generate::<Day06>(6, 4));
+    }
+}
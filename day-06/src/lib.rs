@@ -0,0 +1,183 @@
+pub mod generator;
+pub mod race;
+pub mod reference;
+
+use common::error::{column_of, ParseError};
+use common::solution::{Answer, Solution};
+use race::{ways_to_win, Race, Sheet};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Sheet;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines();
+
+        let times = parse_numbers(lines.next().unwrap_or(""), 1, "Time:")?;
+        let distances_line = lines.next().unwrap_or("");
+        let distances = parse_numbers(distances_line, 2, "Distance:")?;
+
+        if distances.len() != times.len() {
+            return Err(ParseError::new(
+                2,
+                distances_line.len() + 1,
+                format!("expected {} distances, one for each race", times.len()),
+            ));
+        }
+
+        // ways to win square the race time, so the longest time is one whose square fits
+        let joined = Race {
+            time: join_numbers(&times, 1, "times", usize::MAX.isqrt())?,
+            record: join_numbers(&distances, 2, "distances", usize::MAX)?,
+        };
+
+        Ok(Sheet {
+            races: times
+                .into_iter()
+                .zip(distances)
+                .map(|((time, _), (record, _))| Race { time, record })
+                .collect(),
+            joined,
+        })
+    }
+
+    fn part_one(sheet: &Self::Input) -> Answer {
+        sheet
+            .races
+            .iter()
+            .map(|race| ways_to_win(race.time, race.record))
+            .product::<usize>()
+            .into()
+    }
+
+    fn part_two(sheet: &Self::Input) -> Answer {
+        ways_to_win(sheet.joined.time, sheet.joined.record).into()
+    }
+}
+
+/// parse a line like `Time:      7  15   30` into each number and its column
+fn parse_numbers(
+    line: &str,
+    line_number: usize,
+    label: &str,
+) -> Result<Vec<(usize, usize)>, ParseError> {
+    let numbers = line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::new(line_number, 1, format!("expected '{}'", label)))?;
+
+    let numbers: Vec<(usize, usize)> = numbers
+        .split_whitespace()
+        .map(|n| {
+            let column = column_of(line, n);
+            n.parse()
+                .map(|number| (number, column))
+                .map_err(|_| ParseError::new(line_number, column, "expected a number"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    if numbers.is_empty() {
+        return Err(ParseError::new(
+            line_number,
+            line.len() + 1,
+            "expected at least one race",
+        ));
+    }
+
+    Ok(numbers)
+}
+
+/// read the numbers on a line as one, as part two does, as long as that is at most `limit`.
+/// Numbers written one after another are at least their product, so this also keeps
+/// part one's product of ways to win within a usize
+fn join_numbers(
+    numbers: &[(usize, usize)],
+    line_number: usize,
+    what: &str,
+    limit: usize,
+) -> Result<usize, ParseError> {
+    numbers.iter().try_fold(0usize, |joined, &(number, column)| {
+        10usize
+            .checked_pow(number.checked_ilog10().unwrap_or(0) + 1)
+            .and_then(|shift| joined.checked_mul(shift))
+            .and_then(|joined| joined.checked_add(number))
+            .filter(|&joined| joined <= limit)
+            .ok_or_else(|| {
+                ParseError::new(
+                    line_number,
+                    column,
+                    format!("expected the {} together to be at most {}", what, limit),
+                )
+            })
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::race::{Race, Sheet};
+    use crate::Day06;
+    use common::error::ParseError;
+    use common::solution::Solution;
+
+    #[test]
+    fn test_parse() {
+        let sheet = Day06::parse("Time:      7  15\nDistance:  9  40\n").unwrap();
+
+        assert_eq!(
+            sheet,
+            Sheet {
+                races: vec![
+                    Race { time: 7, record: 9 },
+                    Race {
+                        time: 15,
+                        record: 40
+                    }
+                ],
+                joined: Race {
+                    time: 715,
+                    record: 940
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Day06::parse("Time: 7 15\nDistance: 9 4O").unwrap_err(),
+            ParseError::new(2, 13, "expected a number")
+        );
+        assert_eq!(
+            Day06::parse("Time: 7 15\nDistance: 9").unwrap_err(),
+            ParseError::new(2, 12, "expected 2 distances, one for each race")
+        );
+        assert_eq!(
+            Day06::parse("Time: 7 15").unwrap_err(),
+            ParseError::new(2, 1, "expected 'Distance:'")
+        );
+        assert_eq!(
+            Day06::parse("Time: 99999 99999\nDistance: 1 1").unwrap_err(),
+            ParseError::new(
+                1,
+                13,
+                format!("expected the times together to be at most {}", usize::MAX.isqrt())
+            )
+        );
+        assert_eq!(
+            Day06::parse("Time: 9999999999\nDistance: 1").unwrap_err(),
+            ParseError::new(
+                1,
+                7,
+                format!("expected the times together to be at most {}", usize::MAX.isqrt())
+            )
+        );
+        assert_eq!(
+            Day06::parse("Time: 7 15\nDistance: 9999999999 9999999999").unwrap_err(),
+            ParseError::new(
+                2,
+                22,
+                format!("expected the distances together to be at most {}", usize::MAX)
+            )
+        );
+    }
+}
@@ -0,0 +1,73 @@
+#[derive(Debug, PartialEq, Eq)]
+pub struct Race {
+    pub time: usize,
+    pub record: usize,
+}
+
+/// the races on a sheet, and the one long race they are when the spaces between the
+/// numbers on each line are bad kerning
+#[derive(Debug, PartialEq, Eq)]
+pub struct Sheet {
+    pub races: Vec<Race>,
+    pub joined: Race,
+}
+
+pub fn distance(race_time: usize, hold_time: usize) -> usize {
+    hold_time * (race_time - hold_time)
+}
+
+/// the holds that win are those strictly between the roots of
+/// hold * (race_time - hold) = record, found with the quadratic formula in whole numbers
+/// since in floating point the square root can round a whole hold out of the answer.
+/// The race time squared has to fit in a usize, which parsing makes sure of
+pub fn ways_to_win(race_time: usize, record: usize) -> usize {
+    // a record too big to multiply by four is further than any hold goes
+    let Some(discriminant) = record
+        .checked_mul(4)
+        .and_then(|record| race_time.pow(2).checked_sub(record))
+    else {
+        return 0;
+    };
+    let common = discriminant.isqrt();
+
+    // the square root rounds down, so these are at most the first winning hold and at
+    // least the last one
+    let mut low = (race_time - common) / 2;
+    let mut high = ((race_time + common) / 2 + 1).min(race_time);
+
+    while low <= high && distance(race_time, low) <= record {
+        low += 1;
+    }
+    if low > high {
+        return 0;
+    }
+
+    while distance(race_time, high) <= record {
+        high -= 1;
+    }
+
+    high - low + 1
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::race::{distance, ways_to_win};
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance(7, 2), 10);
+        assert_eq!(distance(7, 6), 6);
+    }
+
+    #[test]
+    fn test_ways_to_win() {
+        assert_eq!(ways_to_win(7, 9), 4);
+        assert_eq!(ways_to_win(15, 40), 8);
+        assert_eq!(ways_to_win(30, 200), 9);
+        assert_eq!(ways_to_win(71530, 940200), 71503);
+        assert_eq!(ways_to_win(970728689, 1), 970728688);
+        assert_eq!(ways_to_win(4, 4), 0);
+        assert_eq!(ways_to_win(4, 3), 1);
+        assert_eq!(ways_to_win(99999, usize::MAX), 0);
+    }
+}
@@ -0,0 +1,80 @@
+// Ways to win without the quadratic formula or any floating point. The distance a hold
+// goes grows with the hold time up to half the race and shrinks the same way after it,
+// so the winning holds are one run around the middle, whose first hold is found by
+// a binary search over whole milliseconds and whose last mirrors it.
+
+use crate::generator::Params;
+use crate::race::distance;
+use crate::Day06;
+use common::reference::Reference;
+use common::solution::{Answer, Part};
+
+impl Reference for Day06 {
+    /// up to three races whose times together have nine digits, as on real sheets, with
+    /// exact records for odd sizes, which include single races with the longest times
+    fn reference_params(size: usize) -> Self::Params {
+        let races = size.clamp(1, 3);
+
+        Params {
+            races,
+            time_digits: 9 / races as u32,
+            exact_records: !size.is_multiple_of(2),
+        }
+    }
+
+    fn reference(part: Part, sheet: &Self::Input) -> Option<Answer> {
+        let answer = match part {
+            Part::One => sheet
+                .races
+                .iter()
+                .map(|race| count_ways_to_win(race.time, race.record))
+                .product(),
+            Part::Two => count_ways_to_win(sheet.joined.time, sheet.joined.record),
+        };
+
+        Some(answer.into())
+    }
+}
+
+pub fn count_ways_to_win(race_time: usize, record: usize) -> usize {
+    let beats = |hold_time: usize| distance(race_time, hold_time) > record;
+    let middle = race_time / 2;
+    if !beats(middle) {
+        return 0;
+    }
+
+    // no hold of 0 ever wins, so the first hold that does is in (losing, winning]
+    let (mut losing, mut winning) = (0, middle);
+    while winning - losing > 1 {
+        let hold_time = losing + (winning - losing) / 2;
+        if beats(hold_time) {
+            winning = hold_time;
+        } else {
+            losing = hold_time;
+        }
+    }
+
+    // holding for the race time less the first winning hold goes just as far
+    race_time - 2 * winning + 1
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::reference::count_ways_to_win;
+    use crate::Day06;
+    use common::reference::differential;
+
+    #[test]
+    fn test_count_ways_to_win() {
+        assert_eq!(count_ways_to_win(7, 9), 4);
+        assert_eq!(count_ways_to_win(30, 200), 9);
+        assert_eq!(count_ways_to_win(71530, 940200), 71503);
+        assert_eq!(count_ways_to_win(123456789, 1), 123456788);
+        assert_eq!(count_ways_to_win(4, 4), 0);
+    }
+
+    #[test]
+    fn test_differential() {
+        assert!(differential::<Day06>(50, 3).is_none());
+    }
+}
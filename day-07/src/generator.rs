@@ -0,0 +1,79 @@
+// Camel card hands with bids. Hands are dealt by first picking a kind, so the rarer
+// kinds like five of a kind turn up about as often as high cards.
+
+use crate::Day07;
+use common::generator::Generator;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const LABELS: &[u8] = b"23456789TJQKA";
+
+/// how many cards share each label, for each kind of hand
+const SHAPES: [&[usize]; 7] = [
+    &[5],
+    &[4, 1],
+    &[3, 2],
+    &[3, 1, 1],
+    &[2, 2, 1],
+    &[2, 1, 1, 1],
+    &[1, 1, 1, 1, 1],
+];
+
+pub struct Params {
+    pub hands: usize,
+    pub max_bid: usize,
+}
+
+impl Generator for Day07 {
+    type Params = Params;
+
+    fn params(size: usize) -> Self::Params {
+        Params {
+            hands: size,
+            max_bid: 1000,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, params: &Self::Params) -> String {
+        let mut hands = String::new();
+
+        for _ in 0..params.hands {
+            let shape = SHAPES.choose(rng).unwrap();
+            let labels = LABELS.choose_multiple(rng, shape.len());
+
+            let mut cards: Vec<u8> = labels
+                .zip(shape.iter())
+                .flat_map(|(&label, &count)| [label].repeat(count))
+                .collect();
+            cards.shuffle(rng);
+
+            hands.push_str(&format!(
+                "{} {}\n",
+                String::from_utf8(cards).unwrap(),
+                rng.gen_range(1..=params.max_bid)
+            ));
+        }
+
+        hands
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::Day07;
+    use common::generator::generate;
+    use common::solution::solve;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day07>(7, 1000);
+
+        assert_eq!(input, generate::<Day07>(7, 1000));
+        assert_eq!(input.lines().count(), 1000);
+        assert!(input
+            .lines()
+            .any(|line| line[1..5].bytes().all(|b| b == line.as_bytes()[0])));
+        assert!(solve::<Day07>(&input, None).is_ok());
+    }
+}
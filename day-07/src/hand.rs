@@ -1,8 +1,11 @@
 // 1 = Joker
 // J = Jack
 
+use common::error::{column_of, Located};
 use std::collections::HashMap;
+use std::fmt;
 use std::str::FromStr;
+use ParseHandError::*;
 
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq)]
 pub enum Kind {
@@ -23,24 +26,38 @@ pub struct Hand {
 }
 
 impl Hand {
-    pub fn parse_cards(s: &str) -> [u8; 5] {
-        let cards: [u8; 5] = s
-            .as_bytes()
-            .iter()
-            .map(|&char_byte| match char_byte {
-                b'A' => 14,
-                b'K' => 13,
-                b'Q' => 12,
-                b'J' => 11,
-                b'T' => 10,
-                n if n.is_ascii_digit() => n - b'0',
-                _ => unreachable!(),
+    pub fn parse_cards(s: &str) -> Result<[u8; 5], ParseHandError> {
+        let cards: Vec<u8> = s
+            .char_indices()
+            .map(|(i, card)| match card {
+                'A' => Ok(14),
+                'K' => Ok(13),
+                'Q' => Ok(12),
+                'J' => Ok(11),
+                'T' => Ok(10),
+                '2'..='9' => Ok(card as u8 - b'0'),
+                _ => Err(InvalidCard {
+                    column: i + 1,
+                    card,
+                }),
             })
-            .collect::<Vec<u8>>()
-            .try_into()
-            .expect("hand is exactly five cards");
+            .collect::<Result<_, _>>()?;
 
+        let count = cards.len();
         cards
+            .try_into()
+            .map_err(|_| WrongCardCount { column: 1, count })
+    }
+
+    /// the same hand if every J is read as a Joker instead of a Jack
+    pub fn with_jokers(&self) -> Hand {
+        let cards = self.cards.map(|card| if card == 11 { 1 } else { card });
+
+        Hand {
+            kind: Hand::calculate_kind(&cards),
+            cards,
+            bid: self.bid,
+        }
     }
 
     pub fn calculate_kind(cards: &[u8; 5]) -> Kind {
@@ -58,7 +75,7 @@ impl Hand {
                 n => {
                     let most_prevalent_card = card_counts
                         .iter()
-                        .max_by(|a, b| a.1.cmp(&b.1))
+                        .max_by(|a, b| a.1.cmp(b.1))
                         .map(|(card, _count)| card)
                         .unwrap();
 
@@ -86,17 +103,52 @@ impl Hand {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-pub struct ParseHandError;
+pub enum ParseHandError {
+    InvalidCard { column: usize, card: char },
+    WrongCardCount { column: usize, count: usize },
+    MissingBid { column: usize },
+    InvalidBid { column: usize },
+}
+
+impl fmt::Display for ParseHandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InvalidCard { card, .. } => {
+                write!(f, "expected one of AKQJT98765432 but found '{}'", card)
+            }
+            WrongCardCount { count, .. } => {
+                write!(f, "expected exactly five cards but found {}", count)
+            }
+            MissingBid { .. } => write!(f, "expected a bid after the cards"),
+            InvalidBid { .. } => write!(f, "expected the bid to be a number"),
+        }
+    }
+}
+
+impl Located for ParseHandError {
+    fn column(&self) -> usize {
+        match self {
+            InvalidCard { column, .. }
+            | WrongCardCount { column, .. }
+            | MissingBid { column }
+            | InvalidBid { column } => *column,
+        }
+    }
+}
 
 impl FromStr for Hand {
     type Err = ParseHandError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split(' ').collect();
+        let (cards, bid) = s.split_once(' ').ok_or(MissingBid {
+            column: s.len() + 1,
+        })?;
 
-        let cards: [u8; 5] = Hand::parse_cards(&parts[0]);
+        let cards: [u8; 5] = Hand::parse_cards(cards)?;
         let kind = Hand::calculate_kind(&cards);
-        let bid = parts[1].parse().unwrap();
+        let bid = bid.parse().map_err(|_| InvalidBid {
+            column: column_of(s, bid),
+        })?;
 
         Ok(Hand { kind, cards, bid })
     }
@@ -104,36 +156,70 @@ impl FromStr for Hand {
 
 #[cfg(test)]
 pub mod tests {
-    use super::{Hand, Kind};
+    use super::{Hand, Kind, ParseHandError::*};
 
     #[test]
     fn test_parse_cards() {
-        assert_eq!(Hand::parse_cards("AKQJT"), [14, 13, 12, 11, 10]);
-        assert_eq!(Hand::parse_cards("3AJ92"), [3, 14, 11, 9, 2]);
-        assert_eq!(Hand::parse_cards("1234J"), [1, 2, 3, 4, 11]);
+        assert_eq!(Hand::parse_cards("AKQJT"), Ok([14, 13, 12, 11, 10]));
+        assert_eq!(Hand::parse_cards("3AJ92"), Ok([3, 14, 11, 9, 2]));
+        assert_eq!(
+            Hand::parse_cards("1234J"),
+            Err(InvalidCard {
+                column: 1,
+                card: '1'
+            })
+        );
+        assert_eq!(
+            Hand::parse_cards("2340J"),
+            Err(InvalidCard {
+                column: 4,
+                card: '0'
+            })
+        );
+        assert_eq!(
+            Hand::parse_cards("AKXJT"),
+            Err(InvalidCard {
+                column: 3,
+                card: 'X'
+            })
+        );
+        assert_eq!(
+            Hand::parse_cards("AKQJ"),
+            Err(WrongCardCount {
+                column: 1,
+                count: 4
+            })
+        );
+    }
+
+    /// the cards with every J as a Joker
+    fn jokers(s: &str) -> [u8; 5] {
+        Hand::parse_cards(s)
+            .unwrap()
+            .map(|card| if card == 11 { 1 } else { card })
     }
 
     #[test]
     fn test_calculate_kind() {
-        let five_of_a_kind = Hand::parse_cards("22221");
+        let five_of_a_kind = jokers("2222J");
         assert_eq!(Hand::calculate_kind(&five_of_a_kind), Kind::FiveOfAKind);
 
-        let four_of_a_kind = Hand::parse_cards("77271");
+        let four_of_a_kind = jokers("7727J");
         assert_eq!(Hand::calculate_kind(&four_of_a_kind), Kind::FourOfAKind);
 
-        let full_house = Hand::parse_cards("77221");
+        let full_house = jokers("7722J");
         assert_eq!(Hand::calculate_kind(&full_house), Kind::FullHouse);
 
-        let three_of_a_kind = Hand::parse_cards("17723");
+        let three_of_a_kind = jokers("J7723");
         assert_eq!(Hand::calculate_kind(&three_of_a_kind), Kind::ThreeOfAKind);
 
-        let two_pair = Hand::parse_cards("77223");
+        let two_pair = Hand::parse_cards("77223").unwrap();
         assert_eq!(Hand::calculate_kind(&two_pair), Kind::TwoPair);
 
-        let one_pair = Hand::parse_cards("71234");
+        let one_pair = jokers("7J234");
         assert_eq!(Hand::calculate_kind(&one_pair), Kind::OnePair);
 
-        let high_card = Hand::parse_cards("72345");
+        let high_card = Hand::parse_cards("72345").unwrap();
         assert_eq!(Hand::calculate_kind(&high_card), Kind::HighCard);
     }
 
@@ -151,6 +237,26 @@ pub mod tests {
         assert_eq!(hand, expected_hand);
     }
 
+    #[test]
+    fn test_parse_hand_errors() {
+        assert_eq!("32T3K".parse::<Hand>(), Err(MissingBid { column: 6 }));
+        assert_eq!("32T3K 7x5".parse::<Hand>(), Err(InvalidBid { column: 7 }));
+    }
+
+    #[test]
+    fn test_with_jokers() {
+        let hand: Hand = "KTJJT 220".parse().unwrap();
+        let expected_hand = Hand {
+            kind: Kind::FourOfAKind,
+            cards: jokers("KTJJT"),
+            bid: 220,
+        };
+
+        assert_eq!(hand.kind, Kind::TwoPair);
+        assert_eq!(hand.with_jokers(), expected_hand);
+        assert_eq!(hand.with_jokers().kind, Kind::FourOfAKind);
+    }
+
     #[test]
     fn test_ordering_with_jacks() {
         let input = std::fs::read_to_string("test-input.txt").unwrap();
@@ -173,10 +279,9 @@ pub mod tests {
     fn test_ordering_with_jokers() {
         let input = std::fs::read_to_string("test-input.txt").unwrap();
         let mut hands: Vec<Hand> = input
-            .replace('J', "1")
             .trim()
             .split('\n')
-            .map(|line| line.parse().unwrap())
+            .map(|line| line.parse::<Hand>().unwrap().with_jokers())
             .collect();
 
         hands.sort_unstable();
@@ -1 +1,37 @@
+pub mod generator;
 pub mod hand;
+
+use common::error::{parse_lines, ParseError};
+use common::solution::{Answer, Solution};
+use hand::Hand;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_one(hands: &Self::Input) -> Answer {
+        let mut hands: Vec<&Hand> = hands.iter().collect();
+        hands.sort_unstable();
+
+        calculate_winnings(hands.into_iter()).into()
+    }
+
+    fn part_two(hands: &Self::Input) -> Answer {
+        let mut hands: Vec<Hand> = hands.iter().map(Hand::with_jokers).collect();
+        hands.sort_unstable();
+
+        calculate_winnings(hands.iter()).into()
+    }
+}
+
+/// expects the hands to already be sorted from weakest to strongest
+fn calculate_winnings<'a>(hands: impl Iterator<Item = &'a Hand>) -> usize {
+    hands
+        .enumerate()
+        .fold(0, |winnings, (i, hand)| winnings + (i + 1) * hand.bid)
+}
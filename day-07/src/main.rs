@@ -1,36 +1,44 @@
 // Advent of Code 2023: Day 7
 // https://adventofcode.com/2023/day/7
-// Usage: `cargo run <input-file>
+// Usage: `cargo run [--format text|json] (<input-file>|-)...
 
-use day_07::hand::Hand;
+use common::input;
+use common::report::{self, Format};
+use common::solution::Solution;
+use day_07::Day07;
 use std::env;
-use std::fs;
+use std::process;
 
 fn main() {
-    let input_filename = env::args().nth(1).expect("please supply an input filename");
-    let input = fs::read_to_string(input_filename).expect("failed to read input");
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let format = report::take_format(&mut args).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(2);
+    });
+    let sources = input::sources(args).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(2);
+    });
 
-    println!(
-        "The total winnings if J is a Jack are {}",
-        calculate_winnings(&input)
-    );
-    println!(
-        "The total winnings if J is a Joker are {}",
-        calculate_winnings(&input.replace('J', "1"))
-    );
-}
+    let succeeded = input::for_each(&sources, format, |source, input| {
+        if format == Format::Json {
+            return report::print_records::<Day07>(7, source, input, None);
+        }
 
-fn calculate_winnings(input: &str) -> usize {
-    let mut hands: Vec<Hand> = input
-        .trim()
-        .split('\n')
-        .map(|hand| hand.parse().unwrap())
-        .collect();
+        let hands = Day07::parse(input)?;
 
-    hands.sort_unstable();
+        println!(
+            "The total winnings if J is a Jack are {}",
+            Day07::part_one(&hands)
+        );
+        println!(
+            "The total winnings if J is a Joker are {}",
+            Day07::part_two(&hands)
+        );
+        Ok(())
+    });
 
-    hands
-        .iter()
-        .enumerate()
-        .fold(0, |winnings, (i, hand)| winnings + (i + 1) * hand.bid)
+    if !succeeded {
+        process::exit(1);
+    }
 }
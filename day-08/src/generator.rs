@@ -0,0 +1,200 @@
+// Networks shaped like the real puzzle's: the number of directions is prime, and each
+// ghost walks a ring of its own whose length is the number of directions times another
+// prime. The end of each ring leads where its ghost's A node does, so the walk repeats,
+// and the first ghost walks from AAA to ZZZ. In the real puzzle each ghost's Z node is
+// the end of its ring and the only Z node on it, which is what lets the steps the ghosts
+// take to meet be the lowest common multiple of the steps each takes to its first Z;
+// the generator can also move the Z node back from the end and put more on the ring.
+
+use crate::{Day08, END, START};
+use common::generator::Generator;
+use primes::is_prime;
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+const ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+pub struct Params {
+    /// rounded up to a prime
+    pub directions: usize,
+    pub ghosts: usize,
+    /// the largest prime a ghost's ring length may be a multiple of
+    pub max_factor: usize,
+    /// whether to move each ghost's Z node back from the end of its ring, by the same
+    /// number of steps for every ghost modulo the number of directions so they still meet
+    pub offset: bool,
+    /// how many more Z nodes to put on each ghost's ring, where they fit
+    pub extra_ends: usize,
+}
+
+impl Generator for Day08 {
+    type Params = Params;
+
+    fn params(size: usize) -> Self::Params {
+        Params {
+            directions: size.max(2),
+            ghosts: 6,
+            max_factor: 31,
+            offset: false,
+            extra_ends: 0,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, params: &Self::Params) -> String {
+        let directions = (params.directions.max(2)..)
+            .find(|&n| is_prime(n as u64))
+            .unwrap();
+        let turns: Vec<usize> = (0..directions).map(|_| rng.gen_range(0..2)).collect();
+
+        let factors: Vec<usize> = (2..=params.max_factor)
+            .filter(|&n| n != directions && is_prime(n as u64))
+            .collect();
+        assert!(
+            factors.len() >= params.ghosts,
+            "not enough primes up to {} for {} ghosts",
+            params.max_factor,
+            params.ghosts
+        );
+        let factors: Vec<usize> = factors
+            .choose_multiple(rng, params.ghosts)
+            .copied()
+            .collect();
+
+        let ring_nodes: usize = factors.iter().map(|factor| directions * factor).sum();
+        let mut names = names(rng, ring_nodes).into_iter();
+        let (starts, ends) =
+            ghost_names(rng, params.ghosts, params.ghosts * (1 + params.extra_ends));
+        let mut ends = ends.into_iter();
+        // the steps short of the end of its ring every ghost's Z node is, modulo the directions
+        let shift = if params.offset {
+            rng.gen_range(0..directions)
+        } else {
+            0
+        };
+
+        let mut nodes: Vec<String> = vec![];
+
+        for (factor, start) in factors.into_iter().zip(starts) {
+            let length = directions * factor;
+            let mut ring = vec![start];
+            ring.extend(names.by_ref().take(length));
+
+            let end = if params.offset {
+                length - shift - directions * rng.gen_range(0..factor)
+            } else {
+                length
+            };
+            ring[end] = ends.next().unwrap();
+
+            let others: Vec<usize> = (1..=length).filter(|&step| step != end).collect();
+            for &step in others.choose_multiple(rng, params.extra_ends) {
+                ring[step] = ends.next().unwrap();
+            }
+
+            let mut children: Vec<[usize; 2]> = vec![[0, 0]; length + 1];
+            for step in 0..length {
+                children[step][turns[step % directions]] = step + 1;
+                children[step][1 - turns[step % directions]] = rng.gen_range(1..=length);
+            }
+            children[length] = children[0];
+
+            for (node, [left, right]) in children.into_iter().enumerate() {
+                nodes.push(format!(
+                    "{} = ({}, {})",
+                    ring[node], ring[left], ring[right]
+                ));
+            }
+        }
+        nodes.shuffle(rng);
+
+        let turns: String = turns.iter().map(|&turn| ['L', 'R'][turn]).collect();
+        format!("{}\n\n{}\n", turns, nodes.join("\n"))
+    }
+}
+
+/// distinct names for the nodes along the rings, none ending in A or Z
+fn names(rng: &mut StdRng, count: usize) -> Vec<String> {
+    let lasts: Vec<u8> = ALPHABET
+        .iter()
+        .copied()
+        .filter(|&c| c != b'A' && c != b'Z')
+        .collect();
+    let n = ALPHABET.len();
+    let available = n * n * lasts.len();
+    assert!(count <= available, "too many nodes to name: {}", count);
+
+    index::sample(rng, available, count)
+        .into_iter()
+        .map(|i| {
+            let bytes = [
+                ALPHABET[i / (n * lasts.len())],
+                ALPHABET[i / lasts.len() % n],
+                lasts[i % lasts.len()],
+            ];
+            String::from_utf8(bytes.to_vec()).unwrap()
+        })
+        .collect()
+}
+
+/// the names of the start and end nodes, with the first ghost going from AAA to ZZZ
+fn ghost_names(rng: &mut StdRng, starts: usize, ends: usize) -> (Vec<String>, Vec<String>) {
+    let prefixes: Vec<String> = ALPHABET
+        .iter()
+        .flat_map(|&a| {
+            ALPHABET
+                .iter()
+                .map(move |&b| String::from_utf8(vec![a, b]).unwrap())
+        })
+        .collect();
+
+    let mut named = |first: &str, last: char, count: usize| -> Vec<String> {
+        let others: Vec<&String> = prefixes
+            .iter()
+            .filter(|prefix| format!("{}{}", prefix, last) != first)
+            .collect();
+        let mut names = vec![first.to_string()];
+        names.extend(
+            others
+                .choose_multiple(rng, count.saturating_sub(1))
+                .map(|prefix| format!("{}{}", prefix, last)),
+        );
+        names.truncate(count);
+        names
+    };
+
+    (named(START, 'A', starts), named(END, 'Z', ends))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::Day08;
+    use common::generator::generate;
+    use common::solution::{Answer, Solution};
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day08>(8, 50);
+        assert_eq!(input, generate::<Day08>(8, 50));
+
+        let network = Day08::parse(&input).unwrap();
+        assert_eq!(network.directions.len(), 53);
+        assert_eq!(
+            network
+                .map
+                .keys()
+                .filter(|node| node.ends_with('A'))
+                .count(),
+            6
+        );
+
+        // every ghost's ring, including the one from AAA, divides the time they all take
+        match (Day08::part_one(&network), Day08::part_two(&network)) {
+            (Answer::Unsigned(one), Answer::Unsigned(all)) => {
+                assert_eq!(one % 53, 0);
+                assert_eq!(all % one, 0);
+            }
+            answers => panic!("unexpected answers {:?}", answers),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+pub mod generator;
+pub mod network;
+pub mod reference;
+
+use common::error::ParseError;
+use common::solution::{Answer, Solution};
+use network::Network;
+
+pub const START: &str = "AAA";
+pub const END: &str = "ZZZ";
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Network::new(input)
+    }
+
+    /// the ghosts' networks, like the one in part two's example, may have no AAA to start
+    /// from; any AAA there is was checked for a way to ZZZ
+    fn part_one(network: &Self::Input) -> Answer {
+        network
+            .steps_from(START, |location| location == END)
+            .map_or(Answer::Unanswerable, Answer::from)
+    }
+
+    fn part_two(network: &Self::Input) -> Answer {
+        network
+            .steps_until_ghosts_meet()
+            .map_or(Answer::Unanswerable, Answer::from)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::Day08;
+    use common::solution::{Answer, Solution};
+    use std::fs;
+
+    #[test]
+    fn test_ghost_example() {
+        // part two's example has no AAA, so only part two has an answer
+        let input = fs::read_to_string("test-input-3.txt").unwrap();
+        let network = Day08::parse(&input).unwrap();
+
+        assert_eq!(Day08::part_one(&network), Answer::Unanswerable);
+        assert_eq!(Day08::part_two(&network), Answer::Unsigned(6));
+    }
+}
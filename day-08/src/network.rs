@@ -0,0 +1,344 @@
+use crate::{END, START};
+use common::error::ParseError;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+pub struct Network {
+    pub directions: Vec<usize>,
+    pub map: HashMap<String, [String; 2]>,
+}
+
+impl Network {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let mut lines = input.trim_end().lines().enumerate();
+
+        let directions_line = lines.next().map_or("", |(_, line)| line);
+        let directions: Vec<_> = directions_line
+            .char_indices()
+            .map(|(i, c)| match c {
+                'L' => Ok(0),
+                'R' => Ok(1),
+                _ => Err(ParseError::new(1, i + 1, "expected 'L' or 'R'")),
+            })
+            .collect::<Result<_, _>>()?;
+
+        if directions.is_empty() {
+            return Err(ParseError::new(1, 1, "expected at least one direction"));
+        }
+
+        if !matches!(lines.next(), Some((_, ""))) {
+            return Err(ParseError::new(
+                2,
+                1,
+                "expected a blank line after the directions",
+            ));
+        }
+
+        let re = Regex::new(r"^(.{3}) = \((.{3}), (.{3})\)$").unwrap();
+
+        let mut map: HashMap<String, [String; 2]> = HashMap::new();
+        let mut node_lines: Vec<(usize, &str)> = vec![];
+        let mut destinations = vec![];
+
+        for (i, line) in lines {
+            let captures = re.captures(line).ok_or_else(|| {
+                ParseError::new(i + 1, 1, "expected a node like 'AAA = (BBB, CCC)'")
+            })?;
+
+            for destination in [captures.get(2), captures.get(3)].into_iter().flatten() {
+                destinations.push((i + 1, destination.start() + 1, destination.as_str()));
+            }
+
+            let (_, map_parts): (&str, [&str; 3]) = captures.extract();
+            node_lines.push((i + 1, map_parts[0]));
+            map.insert(
+                map_parts[0].to_owned(),
+                [map_parts[1].to_owned(), map_parts[2].to_owned()],
+            );
+        }
+
+        if map.is_empty() {
+            return Err(ParseError::new(3, 1, "expected at least one node"));
+        }
+
+        for (line, column, destination) in destinations {
+            if !map.contains_key(destination) {
+                return Err(ParseError::new(
+                    line,
+                    column,
+                    format!("expected '{}' to be a node in the network", destination),
+                ));
+            }
+        }
+
+        let network = Self { directions, map };
+
+        if !network.map.keys().any(|node| node.ends_with('A')) {
+            return Err(ParseError::new(3, 1, "expected a node ending in A"));
+        }
+
+        // the walks to check are the ones the parts take, so neither can loop forever;
+        // whether the ghosts ever meet is left to part two, as finding out is solving it
+        for (line, start) in node_lines {
+            if start == START && network.steps_from(START, |node| node == END).is_none() {
+                return Err(ParseError::new(
+                    line,
+                    1,
+                    format!("expected {} to be reachable from {}", END, START),
+                ));
+            }
+            if start.ends_with('A')
+                && network
+                    .steps_from(start, |node| node.ends_with('Z'))
+                    .is_none()
+            {
+                return Err(ParseError::new(
+                    line,
+                    1,
+                    format!("expected a node ending in Z to be reachable from {}", start),
+                ));
+            }
+        }
+
+        Ok(network)
+    }
+
+    /// the number of steps from the start to the first node the walk reaches that is an
+    /// end, or None if there is no such start or the walk goes round for ever without
+    /// reaching an end
+    pub fn steps_from(&self, start: &str, is_end: impl Fn(&str) -> bool) -> Option<u64> {
+        let mut seen: HashSet<(&str, usize)> = HashSet::new();
+        let mut location = start;
+        let mut steps: u64 = 0;
+
+        for (i, &direction) in self.directions.iter().enumerate().cycle() {
+            if !seen.insert((location, i)) {
+                return None;
+            }
+
+            location = &self.map.get(location)?[direction];
+            steps += 1;
+            if is_end(location) {
+                return Some(steps);
+            }
+        }
+
+        unreachable!("the directions are never empty")
+    }
+
+    /// the number of steps until the ghosts starting from every node ending in A are all
+    /// on nodes ending in Z at once, or None if that never happens; unlike the lowest
+    /// common multiple of the steps each ghost takes to its first Z, this holds however
+    /// the Z nodes lie along the walks
+    pub fn steps_until_ghosts_meet(&self) -> Option<u64> {
+        let walks: Vec<Walk> = self
+            .map
+            .keys()
+            .filter(|node| node.ends_with('A'))
+            .map(|start| self.walk(start))
+            .collect();
+
+        // until every walk repeats, just try each step
+        let settled = walks.iter().map(|walk| walk.start).max()?.max(1);
+        if let Some(steps) = (1..settled).find(|&steps| walks.iter().all(|walk| walk.is_end(steps)))
+        {
+            return Some(steps);
+        }
+
+        // after that, each ghost is on a Z node on the steps in some residues modulo its period
+        let mut residues: Vec<(u64, u64)> = vec![(0, 1)];
+        for walk in &walks {
+            residues = residues
+                .iter()
+                .flat_map(|&residue| {
+                    walk.repeating
+                        .iter()
+                        .filter_map(move |&end| chinese_remainder(residue, (end, walk.period)))
+                })
+                .collect();
+            residues.sort_unstable();
+            residues.dedup();
+        }
+
+        residues
+            .into_iter()
+            .map(|(r, m)| {
+                let behind = settled.saturating_sub(r);
+                r + behind.div_ceil(m) * m
+            })
+            .min()
+    }
+
+    /// follow the directions from the node until the walk repeats
+    fn walk(&self, from: &str) -> Walk {
+        let mut seen: HashMap<(&str, usize), u64> = HashMap::new();
+        let mut location = from;
+        let mut ends = vec![];
+
+        for (steps, (i, &direction)) in (0..).zip(self.directions.iter().enumerate().cycle()) {
+            if steps > 0 && location.ends_with('Z') {
+                ends.push(steps);
+            }
+
+            if let Some(&start) = seen.get(&(location, i)) {
+                let period = steps - start;
+                let (once, repeating): (Vec<u64>, Vec<u64>) =
+                    ends.into_iter().partition(|&end| end < start);
+                let mut repeating: Vec<u64> =
+                    repeating.into_iter().map(|end| end % period).collect();
+                repeating.sort_unstable();
+                repeating.dedup();
+
+                return Walk {
+                    start,
+                    period,
+                    once,
+                    repeating,
+                };
+            }
+            seen.insert((location, i), steps);
+
+            location = &self.map[location][direction];
+        }
+
+        unreachable!("the directions are never empty")
+    }
+}
+
+/// A ghost's walk, which from `start` steps on repeats every `period` steps.
+struct Walk {
+    start: u64,
+    period: u64,
+    /// the steps before `start` on which the ghost stands on a Z node
+    once: Vec<u64>,
+    /// the steps from `start` on which it does, modulo the period
+    repeating: Vec<u64>,
+}
+
+impl Walk {
+    fn is_end(&self, steps: u64) -> bool {
+        if steps < self.start {
+            self.once.contains(&steps)
+        } else {
+            self.repeating.contains(&(steps % self.period))
+        }
+    }
+}
+
+/// the step t solving t = r1 mod m1 and t = r2 mod m2, modulo the lowest common multiple
+/// of m1 and m2, which is returned with it; None if there is no such step
+fn chinese_remainder((r1, m1): (u64, u64), (r2, m2): (u64, u64)) -> Option<(u64, u64)> {
+    let (r1, m1, r2, m2) = (r1 as i128, m1 as i128, r2 as i128, m2 as i128);
+    let (g, inverse, _) = extended_gcd(m1, m2);
+    if (r2 - r1) % g != 0 {
+        return None;
+    }
+
+    let lcm = m1 / g * m2;
+    // m1 * inverse = g mod m2, so stepping r1 by m1 that many times lands on r2 mod m2
+    let k = ((r2 - r1) / g * inverse).rem_euclid(m2 / g);
+    let r = (r1 + m1 * k).rem_euclid(lcm);
+
+    Some((u64::try_from(r).ok()?, u64::try_from(lcm).ok()?))
+}
+
+/// (gcd(a, b), x, y) such that a * x + b * y = gcd(a, b)
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::network::{chinese_remainder, Network};
+    use common::error::ParseError;
+
+    #[test]
+    fn test_new() {
+        let network =
+            Network::new("LR\n\nAAA = (BBB, AAA)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+
+        assert_eq!(network.directions, vec![0, 1]);
+        assert_eq!(
+            network.map["AAA"],
+            [String::from("BBB"), String::from("AAA")]
+        );
+    }
+
+    #[test]
+    fn test_new_errors() {
+        assert_eq!(
+            Network::new("LXR\n\nAAA = (AAA, AAA)").err(),
+            Some(ParseError::new(1, 2, "expected 'L' or 'R'"))
+        );
+        assert_eq!(
+            Network::new("LR\n\nAAA = (AAA, AAA)\nBBB = AAA").err(),
+            Some(ParseError::new(
+                4,
+                1,
+                "expected a node like 'AAA = (BBB, CCC)'"
+            ))
+        );
+        assert_eq!(
+            Network::new("LR\n\nAAA = (AAA, ZZZ)").err(),
+            Some(ParseError::new(
+                3,
+                13,
+                "expected 'ZZZ' to be a node in the network"
+            ))
+        );
+        assert_eq!(
+            Network::new("L\n\nBBB = (BBB, BBB)").err(),
+            Some(ParseError::new(3, 1, "expected a node ending in A"))
+        );
+        assert_eq!(
+            Network::new("LR\n\nZZZ = (ZZZ, ZZZ)\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)").err(),
+            Some(ParseError::new(
+                4,
+                1,
+                "expected ZZZ to be reachable from AAA"
+            ))
+        );
+        assert_eq!(
+            Network::new("L\n\n11A = (11B, 11Z)\n11B = (11A, 11A)\n11Z = (11Z, 11Z)").err(),
+            Some(ParseError::new(
+                3,
+                1,
+                "expected a node ending in Z to be reachable from 11A"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_steps_until_ghosts_meet() {
+        let ghosts = "L\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11B, 11B)\n";
+        // the second ghost first reaches a Z after 1 step and then every 3 steps, so
+        // they meet on step 4 rather than the lowest common multiple of 2 and 1
+        let offset = "22A = (22Z, 22Z)\n22Z = (22B, 22B)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n";
+        let network = Network::new(&format!("{}{}", ghosts, offset)).unwrap();
+
+        assert_eq!(network.steps_until_ghosts_meet(), Some(4));
+        assert_eq!(chinese_remainder((2, 4), (1, 3)), Some((10, 12)));
+        assert_eq!(chinese_remainder((1, 4), (2, 6)), None);
+
+        // the first ghost is on a Z on even steps and this one on odd steps, so never both
+        let apart = "22A = (22Z, 22Z)\n22Z = (22B, 22B)\n22B = (22Z, 22Z)\n";
+        let network = Network::new(&format!("{}{}", ghosts, apart)).unwrap();
+
+        assert_eq!(network.steps_until_ghosts_meet(), None);
+    }
+
+    #[test]
+    fn test_steps_from() {
+        let network =
+            Network::new("RL\n\nAAA = (BBB, AAA)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+
+        assert_eq!(network.steps_from("AAA", |node| node == "ZZZ"), Some(3));
+        assert_eq!(network.steps_from("ZZZ", |node| node == "AAA"), None);
+        assert_eq!(network.steps_from("CCC", |node| node == "ZZZ"), None);
+    }
+}
@@ -0,0 +1,76 @@
+// Part two without assuming each ghost's walk repeats from its first Z: walk every
+// ghost together, one step at a time, until they all stand on Z nodes at once.
+
+use crate::generator::Params;
+use crate::network::Network;
+use crate::Day08;
+use common::reference::Reference;
+use common::solution::{Answer, Part};
+
+impl Reference for Day08 {
+    /// three ghosts on rings of at most 11 times 7 steps, so they meet within a few thousand,
+    /// with their Z nodes moved back from the ends of the rings for even sizes and up to two
+    /// more Z nodes on each ring, so that the first Z each ghost reaches isn't always the one
+    /// they meet on
+    fn reference_params(size: usize) -> Self::Params {
+        Params {
+            directions: size.clamp(2, 11),
+            ghosts: 3,
+            max_factor: 7,
+            offset: size.is_multiple_of(2),
+            extra_ends: size % 3,
+        }
+    }
+
+    fn reference(part: Part, network: &Self::Input) -> Option<Answer> {
+        match part {
+            Part::One => None,
+            Part::Two => Some(steps_until_every_ghost_arrives(network).into()),
+        }
+    }
+}
+
+pub fn steps_until_every_ghost_arrives(network: &Network) -> u64 {
+    let mut locations: Vec<&str> = network
+        .map
+        .keys()
+        .filter(|location| location.ends_with('A'))
+        .map(String::as_str)
+        .collect();
+    let mut steps: u64 = 0;
+
+    for &direction in network.directions.iter().cycle() {
+        for location in &mut locations {
+            *location = &network.map[*location][direction];
+        }
+        steps += 1;
+
+        if locations.iter().all(|location| location.ends_with('Z')) {
+            break;
+        }
+    }
+
+    steps
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::network::Network;
+    use crate::reference::steps_until_every_ghost_arrives;
+    use crate::Day08;
+    use common::reference::differential;
+    use std::fs;
+
+    #[test]
+    fn test_steps_until_every_ghost_arrives() {
+        let input = fs::read_to_string("test-input-3.txt").unwrap();
+        let network = Network::new(&input).unwrap();
+
+        assert_eq!(steps_until_every_ghost_arrives(&network), 6);
+    }
+
+    #[test]
+    fn test_differential() {
+        assert!(differential::<Day08>(50, 11).is_none());
+    }
+}
@@ -0,0 +1,93 @@
+// Sequences of a polynomial's values at 0, 1, 2 and so on. The polynomial is built
+// from its differences at 0, so its values are always whole numbers, and its degree
+// leaves the sequence long enough for the differences to reach all zeros.
+
+use crate::Day09;
+use common::generator::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+pub struct Params {
+    pub sequences: usize,
+    pub length: usize,
+    pub max_degree: usize,
+    /// the largest size of each of the polynomial's differences at 0
+    pub max_difference: isize,
+}
+
+impl Generator for Day09 {
+    type Params = Params;
+
+    fn params(size: usize) -> Self::Params {
+        Params {
+            sequences: size,
+            length: 21,
+            max_degree: 8,
+            max_difference: 10,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, params: &Self::Params) -> String {
+        let mut report = String::new();
+
+        for _ in 0..params.sequences {
+            let degree = rng.gen_range(0..=params.max_degree.min(params.length.saturating_sub(2)));
+            let differences: Vec<isize> = (0..=degree)
+                .map(|_| rng.gen_range(-params.max_difference..=params.max_difference))
+                .collect();
+
+            let values: Vec<String> = (0..params.length as isize)
+                .map(|x| value(&differences, x).to_string())
+                .collect();
+
+            report.push_str(&values.join(" "));
+            report.push('\n');
+        }
+
+        report
+    }
+}
+
+/// the value at x of the polynomial with the given differences at 0, by Newton's
+/// forward difference formula: the sum of each kth difference times x choose k
+fn value(differences: &[isize], x: isize) -> isize {
+    let mut choose = 1;
+    let mut value = 0;
+
+    for (k, difference) in differences.iter().enumerate() {
+        value += difference * choose;
+        choose = choose * (x - k as isize) / (k as isize + 1);
+    }
+
+    value
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::generator::value;
+    use crate::sequence::{next_in_sequence, previous_in_sequence};
+    use crate::Day09;
+    use common::generator::generate;
+    use common::solution::solve;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day09>(9, 200);
+
+        assert_eq!(input, generate::<Day09>(9, 200));
+        assert_eq!(input.lines().count(), 200);
+        assert!(input.lines().all(|line| line.split(' ').count() == 21));
+        assert!(solve::<Day09>(&input, None).is_ok());
+    }
+
+    #[test]
+    fn test_value() {
+        // x^2 - 1 has differences -1, 1 and 2 at 0
+        let differences = [-1, 1, 2];
+        let values: Vec<isize> = (0..6).map(|x| value(&differences, x)).collect();
+
+        assert_eq!(values, vec![-1, 0, 3, 8, 15, 24]);
+        assert_eq!(next_in_sequence(&values), value(&differences, 6));
+        assert_eq!(previous_in_sequence(&values), value(&differences, -1));
+    }
+}
@@ -0,0 +1,79 @@
+pub mod generator;
+pub mod sequence;
+
+use common::error::{column_of, ParseError};
+use common::solution::{Answer, Solution};
+use sequence::{next_in_sequence, previous_in_sequence};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<isize>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        if input.trim().is_empty() {
+            return Err(ParseError::new(1, 1, "expected some puzzle input"));
+        }
+
+        input
+            .trim_end()
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let sequence: Vec<isize> = line
+                    .split_whitespace()
+                    .map(|num| {
+                        num.parse().map_err(|_| {
+                            ParseError::new(i + 1, column_of(line, num), "expected a number")
+                        })
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                if sequence.is_empty() {
+                    return Err(ParseError::new(i + 1, 1, "expected a sequence of numbers"));
+                }
+
+                Ok(sequence)
+            })
+            .collect()
+    }
+
+    fn part_one(sequences: &Self::Input) -> Answer {
+        sequences
+            .iter()
+            .map(|sequence| next_in_sequence(sequence))
+            .sum::<isize>()
+            .into()
+    }
+
+    fn part_two(sequences: &Self::Input) -> Answer {
+        sequences
+            .iter()
+            .map(|sequence| previous_in_sequence(sequence))
+            .sum::<isize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::Day09;
+    use common::error::ParseError;
+    use common::solution::Solution;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Day09::parse("0 3 6\n-1 -2\n").unwrap(),
+            vec![vec![0, 3, 6], vec![-1, -2]]
+        );
+        assert_eq!(
+            Day09::parse("0 3 6\n1 2.5").unwrap_err(),
+            ParseError::new(2, 3, "expected a number")
+        );
+        assert_eq!(
+            Day09::parse("0 3 6\n\n1 2").unwrap_err(),
+            ParseError::new(2, 1, "expected a sequence of numbers")
+        );
+    }
+}
@@ -1,50 +1,44 @@
 // Advent of Code 2023: Day 9
 // https://adventofcode.com/2023/day/9
-// Usage: `cargo run <input-file>
+// Usage: `cargo run [--format text|json] (<input-file>|-)...
 
+use common::input;
+use common::report::{self, Format};
+use common::solution::Solution;
+use day_09::Day09;
 use std::env;
-use std::fs;
+use std::process;
 
 fn main() {
-    let input_filename = env::args().nth(1).expect("please supply an input filename");
-    let input = fs::read_to_string(input_filename).expect("failed to read input");
-
-    let sequences: Vec<Vec<isize>> = input
-        .trim()
-        .split('\n')
-        .map(|line| line.split(' ').map(|num| num.parse().unwrap()).collect())
-        .collect();
-
-    let nexts: Vec<isize> = sequences.iter().map(next_in_sequence).collect();
-    let sum_of_nexts: isize = nexts.iter().sum();
-
-    let previouses: Vec<isize> = sequences.iter().map(previous_in_sequence).collect();
-    let sum_of_previouses: isize = previouses.iter().sum();
-
-    println!(
-        "The sum of the next items in the sequences is {}",
-        sum_of_nexts
-    );
-    println!(
-        "The sum of the previous items in the sequences is {}",
-        sum_of_previouses
-    );
-}
-
-fn next_in_sequence(sequence: &Vec<isize>) -> isize {
-    if sequence.iter().all(|&val| val == 0) {
-        return 0;
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let format = report::take_format(&mut args).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(2);
+    });
+    let sources = input::sources(args).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(2);
+    });
+
+    let succeeded = input::for_each(&sources, format, |source, input| {
+        if format == Format::Json {
+            return report::print_records::<Day09>(9, source, input, None);
+        }
+
+        let sequences = Day09::parse(input)?;
+
+        println!(
+            "The sum of the next items in the sequences is {}",
+            Day09::part_one(&sequences)
+        );
+        println!(
+            "The sum of the previous items in the sequences is {}",
+            Day09::part_two(&sequences)
+        );
+        Ok(())
+    });
+
+    if !succeeded {
+        process::exit(1);
     }
-    sequence.last().unwrap() + next_in_sequence(&diffs(sequence))
-}
-
-fn previous_in_sequence(sequence: &Vec<isize>) -> isize {
-    if sequence.iter().all(|&val| val == 0) {
-        return 0;
-    }
-    sequence.first().unwrap() - previous_in_sequence(&diffs(sequence))
-}
-
-fn diffs(sequence: &Vec<isize>) -> Vec<isize> {
-    sequence.windows(2).map(|pair| pair[1] - pair[0]).collect()
 }
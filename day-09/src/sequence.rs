@@ -0,0 +1,17 @@
+pub fn next_in_sequence(sequence: &[isize]) -> isize {
+    if sequence.iter().all(|&val| val == 0) {
+        return 0;
+    }
+    sequence.last().unwrap() + next_in_sequence(&diffs(sequence))
+}
+
+pub fn previous_in_sequence(sequence: &[isize]) -> isize {
+    if sequence.iter().all(|&val| val == 0) {
+        return 0;
+    }
+    sequence.first().unwrap() - previous_in_sequence(&diffs(sequence))
+}
+
+fn diffs(sequence: &[isize]) -> Vec<isize> {
+    sequence.windows(2).map(|pair| pair[1] - pair[0]).collect()
+}
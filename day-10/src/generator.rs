@@ -0,0 +1,156 @@
+// Pipe mazes around a guaranteed loop. The loop is the outline of a random shape made
+// of whole columns of unit squares with their corners on tile centres, where each
+// column's squares overlap the previous column's by at least one. Such a shape has no
+// holes and no squares touching only at a corner, so its outline never crosses or
+// touches itself. The tiles off the loop are ground or stray pipes, except that none
+// of the start's neighbours off the loop connects to it.
+
+use crate::Day10;
+use common::generator::Generator;
+use grid::Grid;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const PIPES: &[u8] = b"|-LJ7F";
+
+pub struct Params {
+    /// at least 2
+    pub rows: usize,
+    /// at least 2
+    pub columns: usize,
+    /// the chance of a tile off the loop being a stray pipe rather than ground
+    pub stray_pipes: f64,
+}
+
+impl Generator for Day10 {
+    type Params = Params;
+
+    fn params(size: usize) -> Self::Params {
+        Params {
+            rows: size.max(2),
+            columns: size.max(2),
+            stray_pipes: 0.5,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, params: &Self::Params) -> String {
+        let shape = shape(rng, params.rows - 1, params.columns - 1);
+        let inside = |row: usize, column: usize, up: bool, left: bool| {
+            let row = if up { row.checked_sub(1) } else { Some(row) };
+            let column = if left {
+                column.checked_sub(1)
+            } else {
+                Some(column)
+            };
+            row.zip(column)
+                .and_then(|(row, column)| shape.get(row, column))
+                .is_some_and(|&inside| inside)
+        };
+
+        let mut tiles = vec![b'.'; params.rows * params.columns];
+        let mut loop_tiles = vec![];
+
+        for row in 0..params.rows {
+            for column in 0..params.columns {
+                // a side of the outline runs from a tile wherever the squares either side of it differ
+                let up = inside(row, column, true, true) != inside(row, column, true, false);
+                let down = inside(row, column, false, true) != inside(row, column, false, false);
+                let left = inside(row, column, true, true) != inside(row, column, false, true);
+                let right = inside(row, column, true, false) != inside(row, column, false, false);
+
+                let i = row * params.columns + column;
+                tiles[i] = match (up, right, down, left) {
+                    (true, false, true, false) => b'|',
+                    (false, true, false, true) => b'-',
+                    (true, true, false, false) => b'L',
+                    (true, false, false, true) => b'J',
+                    (false, false, true, true) => b'7',
+                    (false, true, true, false) => b'F',
+                    _ if rng.gen_bool(params.stray_pipes) => *PIPES.choose(rng).unwrap(),
+                    _ => b'.',
+                };
+
+                if up || right || down || left {
+                    loop_tiles.push(i);
+                }
+            }
+        }
+
+        let mut map = Grid::new(tiles, params.columns);
+        let start = *loop_tiles.choose(rng).unwrap();
+        let neighbours: Vec<usize> = map.neighbours(start).collect();
+
+        for i in neighbours {
+            if !loop_tiles.contains(&i) {
+                map[i] = b'.';
+            }
+        }
+        map[start] = b'S';
+
+        map.to_string()
+    }
+}
+
+/// a random shape of squares, as a column by column run of rows in each column
+fn shape(rng: &mut StdRng, rows: usize, columns: usize) -> Grid<bool> {
+    let quarter = columns.div_ceil(4);
+    let left = rng.gen_range(0..quarter);
+    let right = rng.gen_range((columns - quarter).max(left)..columns);
+
+    let mut inside = vec![false; rows * columns];
+    let mut top = rng.gen_range(0..rows);
+    let mut bottom = rng.gen_range(top..rows);
+
+    for column in left..=right {
+        if column > left {
+            // overlap the previous column's run by at least one square
+            let previous_top = top;
+            top = rng.gen_range(0..=bottom);
+            bottom = rng.gen_range(top.max(previous_top)..rows);
+        }
+
+        for row in top..=bottom {
+            inside[row * columns + column] = true;
+        }
+    }
+
+    Grid::new(inside, columns)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::generator::Params;
+    use crate::Day10;
+    use common::generator::{generate, Generator};
+    use common::solution::{solve, Answer, Solution};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let input = generate::<Day10>(seed, 2 + seed as usize * 3);
+            assert!(solve::<Day10>(&input, None).is_ok());
+        }
+
+        assert_eq!(generate::<Day10>(10, 140), generate::<Day10>(10, 140));
+    }
+
+    #[test]
+    fn test_generate_loop_only() {
+        let params = Params {
+            rows: 30,
+            columns: 40,
+            stray_pipes: 0.0,
+        };
+        let input = Day10::generate(&mut StdRng::seed_from_u64(10), &params);
+        let loop_length = input.bytes().filter(|&b| b != b'.' && b != b'\n').count();
+
+        let map = Day10::parse(&input).unwrap();
+        assert_eq!(
+            Day10::part_one(&map),
+            Answer::Unsigned(loop_length as u64 / 2)
+        );
+    }
+}
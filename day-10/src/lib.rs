@@ -1 +1,31 @@
+pub mod generator;
 pub mod map;
+pub mod reference;
+
+use common::error::ParseError;
+use common::solution::{Answer, Solution};
+use map::Map;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+
+    /// both parts need the loop, so it is found up front
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let to_parse_error = |error| ParseError::at_line(1, &error);
+
+        let mut map = Map::new(input).map_err(to_parse_error)?;
+        map.compute_connections();
+        map.find_loop_path().map_err(to_parse_error)?;
+        Ok(map)
+    }
+
+    fn part_one(map: &Self::Input) -> Answer {
+        map.steps_to_farthest_part_of_loop().into()
+    }
+
+    fn part_two(map: &Self::Input) -> Answer {
+        map.tiles_inside_loop().into()
+    }
+}
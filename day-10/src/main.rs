@@ -1,26 +1,44 @@
 // Advent of Code 2023: Day 10
 // https://adventofcode.com/2023/day/10
-// Usage: `cargo run <input-file>
+// Usage: `cargo run [--format text|json] (<input-file>|-)...
 
-use day_10::map::Map;
+use common::input;
+use common::report::{self, Format};
+use common::solution::Solution;
+use day_10::Day10;
 use std::env;
-use std::fs;
+use std::process;
 
 fn main() {
-    let input_filename = env::args().nth(1).expect("please supply an input filename");
-    let input = fs::read_to_string(input_filename).expect("failed to read input");
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let format = report::take_format(&mut args).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(2);
+    });
+    let sources = input::sources(args).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(2);
+    });
 
-    let mut map = Map::new(&input);
-    map.compute_connections();
-    map.find_loop_path();
+    let succeeded = input::for_each(&sources, format, |source, input| {
+        if format == Format::Json {
+            return report::print_records::<Day10>(10, source, input, None);
+        }
 
-    println!(
-        "The farthest location is {} steps away",
-        map.steps_to_farthest_part_of_loop()
-    );
+        let map = Day10::parse(input)?;
 
-    println!(
-        "The number of tiles enclosed by the loop are {}",
-        map.tiles_inside_loop()
-    )
+        println!(
+            "The farthest location is {} steps away",
+            Day10::part_one(&map)
+        );
+        println!(
+            "The number of tiles enclosed by the loop are {}",
+            Day10::part_two(&map)
+        );
+        Ok(())
+    });
+
+    if !succeeded {
+        process::exit(1);
+    }
 }
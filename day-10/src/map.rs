@@ -1,5 +1,9 @@
+use common::error::Located;
+use grid::{Direction, Direction::*, Grid, ParseGridError};
+use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashMap};
-use Direction::*;
+use std::fmt;
+use ParseMapError::*;
 
 #[derive(Debug, PartialEq, Eq)]
 struct State {
@@ -34,47 +38,124 @@ impl PartialOrd for State {
 }
 
 pub struct Map {
-    map: Box<[u8]>,
+    map: Grid<u8>,
     start: usize,
-    columns: usize,
-    rows: usize,
     connections: HashMap<usize, [Option<usize>; 4]>,
     loop_path: Vec<usize>,
     path_indices: HashMap<usize, usize>,
 }
 
-enum Direction {
-    Up,
-    Left,
-    Down,
-    Right,
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseMapError {
+    InvalidTile {
+        line: usize,
+        column: usize,
+        tile: char,
+    },
+    RaggedRow {
+        line: usize,
+        column: usize,
+        expected: usize,
+    },
+    ExtraStart {
+        line: usize,
+        column: usize,
+    },
+    MissingStart,
+    NoLoop {
+        line: usize,
+        column: usize,
+    },
+}
+
+impl fmt::Display for ParseMapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InvalidTile { tile, .. } => {
+                write!(f, "expected one of |-LJ7F.S but found '{}'", tile)
+            }
+            RaggedRow { expected, .. } => {
+                write!(f, "expected every row to have {} tiles", expected)
+            }
+            ExtraStart { .. } => write!(f, "expected only one S"),
+            MissingStart => write!(f, "expected an S marking the start"),
+            NoLoop { .. } => write!(f, "expected the S to be part of a loop"),
+        }
+    }
+}
+
+impl Located for ParseMapError {
+    fn line(&self) -> usize {
+        match self {
+            InvalidTile { line, .. }
+            | RaggedRow { line, .. }
+            | ExtraStart { line, .. }
+            | NoLoop { line, .. } => *line,
+            MissingStart => 1,
+        }
+    }
+
+    fn column(&self) -> usize {
+        match self {
+            InvalidTile { column, .. }
+            | RaggedRow { column, .. }
+            | ExtraStart { column, .. }
+            | NoLoop { column, .. } => *column,
+            MissingStart => 1,
+        }
+    }
 }
 
 impl Map {
-    pub fn new(input: &str) -> Self {
-        let columns = input.trim().find('\n').unwrap();
-        let input_without_newlines = input.trim().replace('\n', "");
-        let map = input_without_newlines.as_bytes();
-        let rows = map.len() / columns;
-        let start = map.iter().position(|&b| b == b'S').unwrap();
+    pub fn new(input: &str) -> Result<Self, ParseMapError> {
+        let map: Grid<u8> = input.trim().parse().map_err(|error| match error {
+            ParseGridError::Empty => MissingStart,
+            ParseGridError::RaggedRow {
+                line,
+                column,
+                expected,
+            } => RaggedRow {
+                line,
+                column,
+                expected,
+            },
+        })?;
+
+        let mut start = None;
+
+        for (i, &tile) in map.cells().iter().enumerate() {
+            let (line, column) = (map.row(i) + 1, map.column(i) + 1);
+            match tile {
+                b'|' | b'-' | b'L' | b'J' | b'7' | b'F' | b'.' => {}
+                b'S' if start.is_none() => start = Some(i),
+                b'S' => return Err(ExtraStart { line, column }),
+                _ => {
+                    return Err(InvalidTile {
+                        line,
+                        column,
+                        tile: tile.into(),
+                    })
+                }
+            }
+        }
 
-        Self {
-            map: map.into(),
-            columns,
-            rows,
-            start,
+        let start = start.ok_or(MissingStart)?;
+
+        Ok(Self {
             connections: HashMap::with_capacity(map.len()),
+            map,
+            start,
             loop_path: vec![],
             path_indices: HashMap::new(),
-        }
+        })
     }
 
-    fn column(&self, i: usize) -> usize {
-        i % self.columns
+    pub fn tiles(&self) -> &Grid<u8> {
+        &self.map
     }
 
-    fn row(&self, i: usize) -> usize {
-        i / self.columns
+    pub fn start(&self) -> usize {
+        self.start
     }
 
     fn directions(pipe: u8) -> [Option<Direction>; 4] {
@@ -118,34 +199,34 @@ impl Map {
     fn connections_from(&self, i: usize) -> [Option<usize>; 4] {
         let mut connections: [Option<usize>; 4] = [None; 4];
 
-        let can_go_up = i >= self.columns;
-        let can_go_right = self.column(i) < self.columns - 1;
-        let can_go_down = self.row(i) < self.rows - 1;
-        let can_go_left = self.column(i) > 0;
-
         for (n, direction) in Self::directions(self.map[i]).into_iter().enumerate() {
-            match direction {
-                Some(Up) => connections[n] = can_go_up.then(|| i - self.columns),
-                Some(Right) => connections[n] = can_go_right.then(|| i + 1),
-                Some(Down) => connections[n] = can_go_down.then(|| i + self.columns),
-                Some(Left) => connections[n] = can_go_left.then(|| i - 1),
-                None => {}
+            if let Some(direction) = direction {
+                connections[n] = self.map.step(i, direction);
             }
         }
 
         connections
     }
 
-    pub fn find_loop_path(&mut self) {
+    pub fn find_loop_path(&mut self) -> Result<(), ParseMapError> {
+        let no_loop = NoLoop {
+            line: self.map.row(self.start) + 1,
+            column: self.map.column(self.start) + 1,
+        };
+
+        if self.connections[&self.start].iter().flatten().count() < 2 {
+            return Err(no_loop);
+        }
+
         let mut min_steps_to_location: HashMap<usize, usize> = HashMap::new();
         let mut queue: BinaryHeap<State> = BinaryHeap::new();
 
         queue.push(State::new(self.start, 0, vec![self.start]));
         min_steps_to_location.insert(self.start, 0);
 
-        // we want to keep track of I think the two longest paths
-        // which would be the paths to the farthest location from both sides of the loop
-        let mut longest_paths: Vec<Vec<usize>> = vec![vec![], vec![]];
+        // we want to keep track of the longest path down each side of the loop from the start,
+        // which is the path to the farthest location from that side
+        let mut longest_paths: HashMap<usize, Vec<usize>> = HashMap::new();
 
         while let Some(state) = queue.pop() {
             for connection in self.connections[&state.location].iter().flatten() {
@@ -157,11 +238,10 @@ impl Map {
                     let mut path = state.path.clone();
                     path.push(*connection);
 
-                    for longest_path in &mut longest_paths {
-                        if path.len() > longest_path.len() {
-                            *longest_path = path.clone();
-                            break;
-                        }
+                    // the side of the loop is the first step taken from the start
+                    let longest_path = longest_paths.entry(path[1]).or_default();
+                    if path.len() > longest_path.len() {
+                        *longest_path = path.clone();
                     }
 
                     *min_steps = steps;
@@ -170,10 +250,21 @@ impl Map {
             }
         }
 
-        assert!(longest_paths[0] != longest_paths[1]);
+        let mut longest_paths: Vec<Vec<usize>> = longest_paths.into_values().collect();
+        longest_paths.sort_by_key(|path| Reverse(path.len()));
 
-        if longest_paths[0].last() == longest_paths[1].last() {
-            longest_paths[1].pop();
+        // the two sides of a loop meet where their farthest locations connect
+        let sides_meet = match longest_paths.as_slice() {
+            [one_side, other_side, ..] => {
+                let farthest = *one_side.last().unwrap();
+                let other_farthest = *other_side.last().unwrap();
+                self.connections[&farthest].contains(&Some(other_farthest))
+            }
+            _ => false,
+        };
+
+        if !sides_meet {
+            return Err(no_loop);
         }
 
         self.loop_path.append(&mut longest_paths[0]);
@@ -184,6 +275,8 @@ impl Map {
         for (path_index, &map_index) in self.loop_path.iter().enumerate() {
             self.path_indices.insert(map_index, path_index);
         }
+
+        Ok(())
     }
 
     pub fn steps_to_farthest_part_of_loop(&self) -> usize {
@@ -195,8 +288,8 @@ impl Map {
         let mut pipe_stack: Vec<u8> = vec![];
         let mut inside = false;
 
-        for (i, tile) in self.map.iter().enumerate() {
-            if self.column(i) == 0 {
+        for (i, tile) in self.map.cells().iter().enumerate() {
+            if self.map.column(i) == 0 {
                 inside = false;
                 pipe_stack.clear();
             }
@@ -281,12 +374,15 @@ impl Map {
     }
 
     fn path_goes_up_from_start(&self) -> bool {
-        self.row(self.start) > 0 && self.adjacent_in_path(self.start, self.start - self.columns)
+        self.map
+            .step(self.start, Up)
+            .is_some_and(|up| self.adjacent_in_path(self.start, up))
     }
 
     fn path_goes_down_from_start(&self) -> bool {
-        self.row(self.start) < self.rows - 1
-            && self.adjacent_in_path(self.start, self.start + self.columns)
+        self.map
+            .step(self.start, Down)
+            .is_some_and(|down| self.adjacent_in_path(self.start, down))
     }
 }
 
@@ -309,9 +405,9 @@ pub mod test {
     fn test_adjacent_in_path() {
         let input = fs::read_to_string("test-input.txt").expect("failed to read input");
 
-        let mut map = Map::new(&input);
+        let mut map = Map::new(&input).unwrap();
         map.compute_connections();
-        map.find_loop_path();
+        map.find_loop_path().unwrap();
 
         // the loop path looks like this
         // [10, 11, 6, 7, 2, 3, 8, 13, 14, 19, 18, 17, 16, 21, 20, 15]
@@ -344,11 +440,56 @@ pub mod test {
         for (file, expected_tiles_enclosed) in inputs_and_outputs {
             let input = fs::read_to_string(file).unwrap();
 
-            let mut map = Map::new(&input);
+            let mut map = Map::new(&input).unwrap();
             map.compute_connections();
-            map.find_loop_path();
+            map.find_loop_path().unwrap();
 
             assert_eq!(map.tiles_inside_loop(), expected_tiles_enclosed);
         }
     }
+
+    #[test]
+    fn test_new_errors() {
+        assert_eq!(
+            Map::new("7-F7-\n.FJ|7\nSJLX7").err(),
+            Some(InvalidTile {
+                line: 3,
+                column: 4,
+                tile: 'X'
+            })
+        );
+        assert_eq!(
+            Map::new("7-F7-\n.FJ|\nSJLL7").err(),
+            Some(RaggedRow {
+                line: 2,
+                column: 5,
+                expected: 5
+            })
+        );
+        assert_eq!(Map::new("7-F7-\n.FJ|7").err(), Some(MissingStart));
+        assert_eq!(
+            Map::new("S-F7-\n.FJ|S").err(),
+            Some(ExtraStart { line: 2, column: 5 })
+        );
+    }
+
+    #[test]
+    fn test_find_loop_path_without_loop() {
+        let mut map = Map::new("...\n.S-\n...").unwrap();
+        map.compute_connections();
+
+        assert_eq!(map.find_loop_path(), Err(NoLoop { line: 2, column: 2 }));
+    }
+
+    #[test]
+    fn test_find_loop_path_around_one_square() {
+        // the side of the loop explored first used to take over both longest paths
+        let mut map = Map::new("FS\nLJ").unwrap();
+        map.compute_connections();
+        map.find_loop_path().unwrap();
+
+        assert_eq!(map.loop_path.len(), 4);
+        assert_eq!(map.steps_to_farthest_part_of_loop(), 2);
+        assert_eq!(map.tiles_inside_loop(), 0);
+    }
 }
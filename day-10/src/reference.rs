@@ -0,0 +1,135 @@
+// Both parts without the path search or the row scan: walk the loop by following the
+// pipes from the start, and find the enclosed tiles by drawing the loop at three times
+// the scale, where squeezing between pipes leaves a real gap, and flooding in from the
+// edge. Whatever ground the flood can't reach is enclosed.
+
+use crate::generator::Params;
+use crate::Day10;
+use common::reference::Reference;
+use common::solution::{Answer, Part};
+use grid::{Direction, Direction::*, Grid};
+
+impl Reference for Day10 {
+    fn reference_params(size: usize) -> Self::Params {
+        Params {
+            rows: size + 1,
+            columns: size + 1,
+            stray_pipes: 0.5,
+        }
+    }
+
+    fn reference(part: Part, map: &Self::Input) -> Option<Answer> {
+        let loop_path = walk_loop(map.tiles(), map.start());
+
+        Some(match part {
+            Part::One => (loop_path.len() / 2).into(),
+            Part::Two => count_enclosed_tiles(map.tiles(), &loop_path).into(),
+        })
+    }
+}
+
+fn openings(tile: u8) -> &'static [Direction] {
+    match tile {
+        b'|' => &[Up, Down],
+        b'-' => &[Left, Right],
+        b'L' => &[Up, Right],
+        b'J' => &[Up, Left],
+        b'7' => &[Down, Left],
+        b'F' => &[Down, Right],
+        _ => &[],
+    }
+}
+
+/// the directions of the start's neighbours with pipes leading back to it
+fn start_openings(tiles: &Grid<u8>, start: usize) -> Vec<Direction> {
+    Direction::ALL
+        .into_iter()
+        .filter(|&direction| {
+            tiles
+                .step(start, direction)
+                .is_some_and(|i| openings(tiles[i]).contains(&direction.opposite()))
+        })
+        .collect()
+}
+
+/// the loop's tiles in order, leaving the start the first way it connects
+pub fn walk_loop(tiles: &Grid<u8>, start: usize) -> Vec<usize> {
+    let mut direction = start_openings(tiles, start)[0];
+    let mut i = tiles.step(start, direction).unwrap();
+    let mut path = vec![start];
+
+    while i != start {
+        path.push(i);
+        direction = *openings(tiles[i])
+            .iter()
+            .find(|&&opening| opening != direction.opposite())
+            .unwrap();
+        i = tiles.step(i, direction).expect("the loop stays on the map");
+    }
+
+    path
+}
+
+pub fn count_enclosed_tiles(tiles: &Grid<u8>, loop_path: &[usize]) -> usize {
+    let scaled_columns = tiles.columns() * 3;
+    let mut walls = Grid::new(vec![false; tiles.len() * 9], scaled_columns);
+    let centre = |i: usize| (tiles.row(i) * 3 + 1) * scaled_columns + tiles.column(i) * 3 + 1;
+
+    for &i in loop_path {
+        let pipe_openings = match tiles[i] {
+            b'S' => start_openings(tiles, i),
+            tile => openings(tile).to_vec(),
+        };
+
+        let middle = centre(i);
+        walls[middle] = true;
+        for direction in pipe_openings {
+            let side = walls.step(middle, direction).unwrap();
+            walls[side] = true;
+        }
+    }
+
+    // the scaled up edge is never a wall, since no pipe on the loop leads off the map
+    let mut outside = walls.map(|_| false);
+    let mut to_visit = vec![0];
+    while let Some(i) = to_visit.pop() {
+        if walls[i] || outside[i] {
+            continue;
+        }
+        outside[i] = true;
+        to_visit.extend(walls.neighbours(i));
+    }
+
+    (0..tiles.len())
+        .filter(|&i| !walls[centre(i)] && !outside[centre(i)])
+        .count()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::map::Map;
+    use crate::reference::{count_enclosed_tiles, walk_loop};
+    use crate::Day10;
+    use common::reference::differential;
+    use std::fs;
+
+    #[test]
+    fn test_count_enclosed_tiles() {
+        for (filename, expected) in [
+            ("part-2-test-1.txt", 4),
+            ("part-2-test-2.txt", 8),
+            ("part-2-test-3.txt", 10),
+        ] {
+            let input = fs::read_to_string(filename).unwrap();
+            let map = Map::new(&input).unwrap();
+            let loop_path = walk_loop(map.tiles(), map.start());
+
+            assert_eq!(count_enclosed_tiles(map.tiles(), &loop_path), expected);
+        }
+    }
+
+    #[test]
+    fn test_differential() {
+        assert!(differential::<Day10>(200, 12).is_none());
+    }
+}
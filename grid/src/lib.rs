@@ -0,0 +1,407 @@
+// A rectangular grid stored row by row in a flat slice, addressed either by
+// index into that slice or by (row, column) coordinates.
+
+use common::error::Located;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+use Direction::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// clockwise from up, the order used by `Grid::neighbours`
+    pub const ALL: [Direction; 4] = [Up, Right, Down, Left];
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Up => Down,
+            Right => Left,
+            Down => Up,
+            Left => Right,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Box<[T]>,
+    columns: usize,
+    rows: usize,
+}
+
+impl<T> Grid<T> {
+    /// panics unless the cells fill a whole number of rows
+    pub fn new(cells: impl Into<Box<[T]>>, columns: usize) -> Self {
+        let cells = cells.into();
+        assert!(columns > 0, "a grid needs at least one column");
+        assert_eq!(cells.len() % columns, 0, "cells must fill every row");
+        let rows = cells.len() / columns;
+
+        Self {
+            cells,
+            columns,
+            rows,
+        }
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn row(&self, i: usize) -> usize {
+        i / self.columns
+    }
+
+    pub fn column(&self, i: usize) -> usize {
+        i % self.columns
+    }
+
+    pub fn coordinates(&self, i: usize) -> (usize, usize) {
+        (self.row(i), self.column(i))
+    }
+
+    pub fn index(&self, row: usize, column: usize) -> Option<usize> {
+        (row < self.rows && column < self.columns).then(|| row * self.columns + column)
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        self.index(row, column).map(|i| &self.cells[i])
+    }
+
+    /// the index one step away in the given direction, if it is still on the grid
+    pub fn step(&self, i: usize, direction: Direction) -> Option<usize> {
+        let (row, column) = self.coordinates(i);
+
+        match direction {
+            Up => (row > 0).then(|| i - self.columns),
+            Right => (column < self.columns - 1).then(|| i + 1),
+            Down => (row < self.rows - 1).then(|| i + self.columns),
+            Left => (column > 0).then(|| i - 1),
+        }
+    }
+
+    /// the up to 4 indices sharing an edge with i, clockwise from up
+    pub fn neighbours(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(i, direction))
+    }
+
+    /// the up to 8 indices sharing an edge or a corner with i, clockwise from up
+    pub fn adjacent(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        const OFFSETS: [(isize, isize); 8] = [
+            (-1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+            (1, 0),
+            (1, -1),
+            (0, -1),
+            (-1, -1),
+        ];
+
+        let (row, column) = self.coordinates(i);
+
+        OFFSETS
+            .into_iter()
+            .filter_map(move |(row_offset, column_offset)| {
+                let row = row.checked_add_signed(row_offset)?;
+                let column = column.checked_add_signed(column_offset)?;
+                self.index(row, column)
+            })
+    }
+
+    pub fn row_cells(&self, row: usize) -> &[T] {
+        &self.cells[(row * self.columns)..((row + 1) * self.columns)]
+    }
+
+    pub fn column_cells(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(column).step_by(self.columns)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.columns)
+    }
+
+    /// every rows x columns sub-grid, left to right and then top to bottom
+    pub fn windows(&self, rows: usize, columns: usize) -> impl Iterator<Item = Window<'_, T>> {
+        let tops = 0..(self.rows + 1).saturating_sub(rows);
+        let lefts = 0..(self.columns + 1).saturating_sub(columns);
+
+        tops.flat_map(move |top| {
+            lefts.clone().map(move |left| Window {
+                grid: self,
+                top,
+                left,
+                rows,
+                columns,
+            })
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            columns: self.columns,
+            rows: self.rows,
+        }
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        &self.cells[i]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.cells[i]
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        assert!(column < self.columns, "column out of bounds");
+        &self.cells[row * self.columns + column]
+    }
+}
+
+/// A rectangular view into part of a grid.
+pub struct Window<'a, T> {
+    grid: &'a Grid<T>,
+    pub top: usize,
+    pub left: usize,
+    pub rows: usize,
+    pub columns: usize,
+}
+
+impl<'a, T> Window<'a, T> {
+    pub fn iter_rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (self.top..(self.top + self.rows))
+            .map(|row| &self.grid.row_cells(row)[self.left..(self.left + self.columns)])
+    }
+
+    /// the grid indices covered by the window, row by row
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        (self.top..(self.top + self.rows)).flat_map(move |row| {
+            (self.left..(self.left + self.columns))
+                .map(move |column| row * self.grid.columns + column)
+        })
+    }
+}
+
+/// Grids of characters print as the text they were parsed from.
+impl<T: Copy + Into<char>> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.iter_rows() {
+            let line: String = row.iter().map(|&cell| cell.into()).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    Empty,
+    RaggedRow {
+        line: usize,
+        column: usize,
+        expected: usize,
+    },
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "expected at least one row"),
+            ParseGridError::RaggedRow { expected, .. } => {
+                write!(f, "expected every row to be {} long", expected)
+            }
+        }
+    }
+}
+
+impl Located for ParseGridError {
+    fn line(&self) -> usize {
+        match self {
+            ParseGridError::Empty => 1,
+            ParseGridError::RaggedRow { line, .. } => *line,
+        }
+    }
+
+    fn column(&self) -> usize {
+        match self {
+            ParseGridError::Empty => 1,
+            ParseGridError::RaggedRow { column, .. } => *column,
+        }
+    }
+}
+
+/// one byte per cell, one line per row
+impl FromStr for Grid<u8> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.trim_end().lines().collect();
+        let columns = lines.first().map_or(0, |line| line.len());
+
+        if columns == 0 {
+            return Err(ParseGridError::Empty);
+        }
+
+        for (row, line) in lines.iter().enumerate() {
+            if line.len() != columns {
+                return Err(ParseGridError::RaggedRow {
+                    line: row + 1,
+                    column: line.len().min(columns) + 1,
+                    expected: columns,
+                });
+            }
+        }
+
+        Ok(Grid::new(lines.concat().into_bytes(), columns))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::{Direction::*, Grid, ParseGridError};
+
+    fn grid() -> Grid<u8> {
+        "abcd\nefgh\nijkl".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+
+        assert_eq!(grid.columns(), 4);
+        assert_eq!(grid.rows(), 3);
+        assert_eq!(grid[5], b'f');
+        assert_eq!(grid[(2, 3)], b'l');
+        assert_eq!(grid.to_string(), "abcd\nefgh\nijkl\n");
+
+        assert_eq!("".parse::<Grid<u8>>(), Err(ParseGridError::Empty));
+        assert_eq!(
+            "abcd\nefg\nijkl".parse::<Grid<u8>>(),
+            Err(ParseGridError::RaggedRow {
+                line: 2,
+                column: 4,
+                expected: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_coordinates() {
+        let grid = grid();
+
+        assert_eq!(grid.coordinates(6), (1, 2));
+        assert_eq!(grid.index(1, 2), Some(6));
+        assert_eq!(grid.index(1, 4), None);
+        assert_eq!(grid.index(3, 0), None);
+        assert_eq!(grid.get(2, 0), Some(&b'i'));
+    }
+
+    #[test]
+    fn test_step() {
+        let grid = grid();
+
+        assert_eq!(grid.step(0, Up), None);
+        assert_eq!(grid.step(0, Left), None);
+        assert_eq!(grid.step(0, Right), Some(1));
+        assert_eq!(grid.step(0, Down), Some(4));
+        assert_eq!(grid.step(11, Right), None);
+        assert_eq!(grid.step(11, Down), None);
+        assert_eq!(Up.opposite(), Down);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+
+        assert_eq!(grid.neighbours(0).collect::<Vec<_>>(), vec![1, 4]);
+        assert_eq!(grid.neighbours(5).collect::<Vec<_>>(), vec![1, 6, 9, 4]);
+        assert_eq!(grid.neighbours(11).collect::<Vec<_>>(), vec![7, 10]);
+    }
+
+    #[test]
+    fn test_adjacent() {
+        let grid = grid();
+
+        assert_eq!(grid.adjacent(0).collect::<Vec<_>>(), vec![1, 5, 4]);
+        assert_eq!(
+            grid.adjacent(5).collect::<Vec<_>>(),
+            vec![1, 2, 6, 10, 9, 8, 4, 0]
+        );
+        assert_eq!(grid.adjacent(11).collect::<Vec<_>>(), vec![7, 10, 6]);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(grid.row_cells(1), b"efgh");
+        assert_eq!(grid.column_cells(2).copied().collect::<Vec<_>>(), b"cgk");
+        assert_eq!(grid.iter_rows().count(), 3);
+    }
+
+    #[test]
+    fn test_windows() {
+        let grid = grid();
+        let windows: Vec<_> = grid.windows(2, 3).collect();
+
+        assert_eq!(windows.len(), 4);
+        assert_eq!((windows[3].top, windows[3].left), (1, 1));
+        assert_eq!(
+            windows[3].iter_rows().collect::<Vec<_>>(),
+            vec![b"fgh", b"jkl"]
+        );
+        assert_eq!(
+            windows[0].indices().collect::<Vec<_>>(),
+            vec![0, 1, 2, 4, 5, 6]
+        );
+        assert_eq!(grid.windows(4, 1).count(), 0);
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = grid().map(|&cell| cell == b'f');
+
+        assert!(grid[5]);
+        assert!(!grid[4]);
+
+        let mut grid = grid;
+        grid[4] = true;
+        assert!(grid[4]);
+    }
+}